@@ -1,12 +1,14 @@
 """Common utility fonctions."""
 
+import os
 from pathlib import Path
 from time import perf_counter_ns
 from typing import Callable
 
 
 def get_input_data(day: str = "01") -> str:
-    input_path = Path(__file__).parent.parent / f"data/input-{day}.txt"
+    data_dir = os.environ.get("AOC_DATA_DIR", Path(__file__).parent.parent / "data")
+    input_path = Path(data_dir) / f"input-{day}.txt"
     return input_path.read_text("utf-8")
 
 
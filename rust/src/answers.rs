@@ -0,0 +1,362 @@
+use crate::input::find_data_dir;
+use crate::Part;
+use anyhow::{anyhow, bail, Context, Result};
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+pub const ANSWERS_FILE_NAME: &str = "answers.toml";
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+struct AnswerKey {
+    day: String,
+    input_name: String,
+}
+
+// An answer along with the comments written above it and after it on its line
+#[derive(Debug, Default, Clone, PartialEq)]
+struct Answer {
+    value: String,
+    comments: Vec<String>,
+    inline_comment: Option<String>,
+}
+
+// Comments are kept so that recording answers doesn't wipe them: the ones right above a header or
+// an answer stay with it, the other ones stay at the end of their section
+#[derive(Debug, Default, Clone, PartialEq)]
+struct Section {
+    comments: Vec<String>,
+    answers: BTreeMap<Part, Answer>,
+    trailing_comments: Vec<String>,
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub struct AnswerStore {
+    // Lines before the first section, blank ones included
+    preamble: Vec<String>,
+    sections: BTreeMap<AnswerKey, Section>,
+}
+
+fn part_key(part: Part) -> String {
+    format!("part_{}", part)
+}
+
+fn parse_part_key(key: &str) -> Option<Part> {
+    Part::ALL.into_iter().find(|part| part_key(*part) == key)
+}
+
+// Splits a leading quoted string from the rest of the value, a '\' escapes a '"' or a '\'
+fn split_quoted(value: &str) -> Option<(String, &str)> {
+    let value = value.strip_prefix('"')?;
+    let mut unquoted = String::new();
+    let mut chars = value.char_indices();
+    while let Some((offset, ch)) = chars.next() {
+        match ch {
+            '"' => return Some((unquoted, &value[offset + 1..])),
+            '\\' => match chars.next()? {
+                (_, escaped @ ('"' | '\\')) => unquoted.push(escaped),
+                _ => return None,
+            },
+            _ => unquoted.push(ch),
+        }
+    }
+    None
+}
+
+fn quote(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+fn parse_header(header: &str) -> Option<AnswerKey> {
+    let (day, rest) = split_quoted(header.trim())?;
+    let rest = rest.trim_start().strip_prefix('.')?;
+    let (input_name, rest) = split_quoted(rest.trim_start())?;
+    if !rest.trim().is_empty() {
+        return None;
+    }
+
+    Some(AnswerKey { day, input_name })
+}
+
+// The answer of a "part_N = ..." value, and its inline comment if any
+fn parse_answer(value: &str) -> Option<(String, Option<String>)> {
+    let (answer, rest) = split_quoted(value.trim())?;
+    let rest = rest.trim();
+    if rest.is_empty() {
+        return Some((answer, None));
+    }
+    rest.starts_with('#')
+        .then(|| (answer, Some(rest.to_string())))
+}
+
+impl AnswerStore {
+    pub fn default_path() -> Result<PathBuf> {
+        Ok(find_data_dir()?.join(ANSWERS_FILE_NAME))
+    }
+
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Could not read answers from {}", path.display()))?;
+        content
+            .parse()
+            .with_context(|| format!("Invalid answers file {}", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        fs::write(path, self.to_string())
+            .with_context(|| format!("Could not write answers to {}", path.display()))
+    }
+
+    pub fn get(&self, day: &str, input_name: &str, part: Part) -> Option<&str> {
+        let key = AnswerKey {
+            day: day.to_string(),
+            input_name: input_name.to_string(),
+        };
+        let answer = self.sections.get(&key)?.answers.get(&part)?;
+        Some(answer.value.as_str())
+    }
+
+    // Sections without any answer, like the ones of scaffolded stubs, don't count
+    pub fn is_empty(&self) -> bool {
+        self.sections
+            .values()
+            .all(|section| section.answers.is_empty())
+    }
+
+    pub fn record(&mut self, day: &str, input_name: &str, part: Part, answer: &str) {
+        let key = AnswerKey {
+            day: day.to_string(),
+            input_name: input_name.to_string(),
+        };
+        self.sections
+            .entry(key)
+            .or_default()
+            .answers
+            .entry(part)
+            .or_default()
+            .value = answer.to_string();
+    }
+}
+
+impl FromStr for AnswerStore {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut store = AnswerStore::default();
+        let mut current_key: Option<AnswerKey> = None;
+        let mut comments: Vec<String> = Vec::new();
+
+        for (index, raw_line) in s.lines().enumerate() {
+            let line = raw_line.trim();
+            if line.starts_with('#') {
+                comments.push(line.to_string());
+                continue;
+            }
+
+            // Comments followed by a blank line belong to what comes before them
+            if line.is_empty() {
+                match &current_key {
+                    None => {
+                        store.preamble.append(&mut comments);
+                        store.preamble.push(String::new());
+                    }
+                    Some(key) => store
+                        .sections
+                        .entry(key.clone())
+                        .or_default()
+                        .trailing_comments
+                        .append(&mut comments),
+                }
+                continue;
+            }
+
+            if let Some(header) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+                let key = parse_header(header)
+                    .ok_or_else(|| anyhow!("line {}: invalid section {}", index + 1, line))?;
+                let section = store.sections.entry(key.clone()).or_default();
+                if current_key.is_none() {
+                    store.preamble.append(&mut comments);
+                } else {
+                    section.comments.append(&mut comments);
+                }
+                current_key = Some(key);
+                continue;
+            }
+
+            let Some(key) = &current_key else {
+                bail!("line {}: answer outside of a section", index + 1);
+            };
+            let (name, value) = line
+                .split_once('=')
+                .ok_or_else(|| anyhow!("line {}: expected 'part_N = \"answer\"'", index + 1))?;
+            let part = parse_part_key(name.trim())
+                .ok_or_else(|| anyhow!("line {}: unknown part {}", index + 1, name.trim()))?;
+            let (value, inline_comment) = parse_answer(value)
+                .ok_or_else(|| anyhow!("line {}: answer must be quoted", index + 1))?;
+
+            store
+                .sections
+                .entry(key.clone())
+                .or_default()
+                .answers
+                .insert(
+                    part,
+                    Answer {
+                        value,
+                        comments: std::mem::take(&mut comments),
+                        inline_comment,
+                    },
+                );
+        }
+
+        match &current_key {
+            None => store.preamble.append(&mut comments),
+            Some(key) => store
+                .sections
+                .entry(key.clone())
+                .or_default()
+                .trailing_comments
+                .append(&mut comments),
+        }
+        Ok(store)
+    }
+}
+
+impl Display for AnswerStore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for line in &self.preamble {
+            writeln!(f, "{}", line)?;
+        }
+        for (i, (key, section)) in self.sections.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            for comment in &section.comments {
+                writeln!(f, "{}", comment)?;
+            }
+            writeln!(f, "[{}.{}]", quote(&key.day), quote(&key.input_name))?;
+            for (part, answer) in &section.answers {
+                for comment in &answer.comments {
+                    writeln!(f, "{}", comment)?;
+                }
+                write!(f, "{} = {}", part_key(*part), quote(&answer.value))?;
+                match &answer.inline_comment {
+                    Some(comment) => writeln!(f, " {}", comment)?,
+                    None => writeln!(f)?,
+                }
+            }
+            for comment in &section.trailing_comments {
+                writeln!(f, "{}", comment)?;
+            }
+        }
+        std::fmt::Result::Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ANSWERS: &str = "# Answers for my inputs
+[\"07\".\"input-07.txt\"]
+part_1 = \"1298103531759\"
+part_2 = \"140575048428831\"
+
+[\"14\".\"example.txt\"]
+part_1 = \"12\"
+";
+
+    #[test]
+    fn should_load_from_str() {
+        let store = AnswerStore::from_str(ANSWERS).unwrap();
+
+        assert_eq!(
+            Some("1298103531759"),
+            store.get("07", "input-07.txt", Part::One)
+        );
+        assert_eq!(
+            Some("140575048428831"),
+            store.get("07", "input-07.txt", Part::Two)
+        );
+        assert_eq!(Some("12"), store.get("14", "example.txt", Part::One));
+        assert_eq!(None, store.get("14", "example.txt", Part::Two));
+        assert_eq!(None, store.get("14", "input-14.txt", Part::One));
+    }
+
+    #[test]
+    fn should_record_and_write_answers() {
+        let expected = "[\"07\".\"input-07.txt\"]
+part_1 = \"1298103531759\"
+part_2 = \"140575048428831\"
+
+[\"14\".\"example.txt\"]
+part_1 = \"12\"
+";
+        let mut store = AnswerStore::default();
+        store.record("14", "example.txt", Part::One, "12");
+        store.record("07", "input-07.txt", Part::Two, "140575048428831");
+        store.record("07", "input-07.txt", Part::One, "1298103531759");
+
+        assert_eq!(expected, store.to_string());
+        assert_eq!(store, AnswerStore::from_str(expected).unwrap());
+    }
+
+    #[test]
+    fn should_know_the_answers_of_the_committed_inputs() {
+        let seed = include_str!("../../data/answers.toml");
+        let store = AnswerStore::from_str(seed).unwrap();
+
+        assert_eq!(Some("4559"), store.get("06", "input-06.txt", Part::One));
+        assert_eq!(Some("1486520"), store.get("15", "input-15.txt", Part::Two));
+
+        // Recording an answer doesn't reformat the seed
+        let mut store = store;
+        store.record("16", "input-16.txt", Part::One, "42");
+        assert_eq!(
+            format!("{}\n[\"16\".\"input-16.txt\"]\npart_1 = \"42\"\n", seed),
+            store.to_string()
+        );
+    }
+
+    #[test]
+    fn should_keep_comments_when_recording() {
+        let commented = "# Answers for my inputs
+
+# Solved twice
+[\"07\".\"input-07.txt\"]
+# Checked by hand
+part_1 = \"1298103531759\" # the slow way
+part_2 = \"140575048428831\"
+# part_3 = \"\"
+
+[\"14\".\"example.txt\"]
+part_1 = \"a \\\"quoted\\\" \\\\ answer\"
+";
+        let mut store = AnswerStore::from_str(commented).unwrap();
+        assert_eq!(
+            Some("1298103531759"),
+            store.get("07", "input-07.txt", Part::One)
+        );
+        assert_eq!(
+            Some("a \"quoted\" \\ answer"),
+            store.get("14", "example.txt", Part::One)
+        );
+        assert_eq!(commented, store.to_string());
+
+        store.record("14", "example.txt", Part::Two, "24");
+        assert_eq!(format!("{}part_2 = \"24\"\n", commented), store.to_string());
+
+        assert!(AnswerStore::from_str("[\"14\".\"example.txt\"]\npart_1 = \"12\" 13").is_err());
+    }
+
+    #[test]
+    fn should_reject_answer_outside_of_section() {
+        assert!(AnswerStore::from_str("part_1 = \"12\"").is_err());
+    }
+}
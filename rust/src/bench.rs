@@ -0,0 +1,166 @@
+use anyhow::{anyhow, bail, Result};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum BenchLimit {
+    Runs(usize),
+    Budget(Duration),
+}
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct BenchOptions {
+    pub warmup_runs: usize,
+    pub limit: BenchLimit,
+}
+
+impl Default for BenchOptions {
+    fn default() -> Self {
+        Self {
+            warmup_runs: 3,
+            limit: BenchLimit::Budget(Duration::from_secs(1)),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct Stats {
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+    pub p95: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        assert!(!samples.is_empty(), "can not compute stats without samples");
+
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let runs = sorted.len();
+
+        let median = if runs.is_multiple_of(2) {
+            (sorted[runs / 2 - 1] + sorted[runs / 2]) / 2
+        } else {
+            sorted[runs / 2]
+        };
+
+        let mean_ns = sorted.iter().map(|d| d.as_nanos() as f64).sum::<f64>() / runs as f64;
+        let variance = sorted
+            .iter()
+            .map(|d| (d.as_nanos() as f64 - mean_ns).powi(2))
+            .sum::<f64>()
+            / runs as f64;
+
+        // Nearest-rank percentile
+        let p95_rank = (0.95 * runs as f64).ceil() as usize;
+
+        Self {
+            runs,
+            min: sorted[0],
+            median,
+            mean: Duration::from_nanos(mean_ns.round() as u64),
+            stddev: Duration::from_nanos(variance.sqrt().round() as u64),
+            p95: sorted[p95_rank.max(1) - 1],
+        }
+    }
+}
+
+pub fn benchmark<T>(options: &BenchOptions, mut function: impl FnMut() -> T) -> Stats {
+    for _ in 0..options.warmup_runs {
+        black_box(function());
+    }
+
+    let mut samples = Vec::new();
+    let bench_start = Instant::now();
+    loop {
+        let start = Instant::now();
+        black_box(function());
+        samples.push(start.elapsed());
+
+        let done = match options.limit {
+            BenchLimit::Runs(runs) => samples.len() >= runs,
+            BenchLimit::Budget(budget) => bench_start.elapsed() >= budget,
+        };
+        if done {
+            break;
+        }
+    }
+
+    Stats::from_samples(&samples)
+}
+
+#[macro_export]
+macro_rules! bench_function {
+    ($options:expr, $expression:expr) => {{
+        $crate::bench::benchmark($options, || $expression)
+    }};
+}
+
+pub fn parse_duration(value: &str) -> Result<Duration> {
+    let split_at = value
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .ok_or_else(|| anyhow!("Missing unit in duration: {} (e.g. 500ms, 2s)", value))?;
+    let (number, unit) = value.split_at(split_at);
+    let number: f64 = number
+        .parse()
+        .map_err(|_| anyhow!("Invalid duration: {}", value))?;
+
+    let seconds = match unit {
+        "ns" => number / 1e9,
+        "us" => number / 1e6,
+        "ms" => number / 1e3,
+        "s" => number,
+        _ => bail!("Invalid duration unit: {} (expected ns, us, ms or s)", unit),
+    };
+    Duration::try_from_secs_f64(seconds)
+        .map_err(|error| anyhow!("Invalid duration: {} ({})", value, error))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_compute_stats() {
+        let samples: Vec<Duration> = [5, 1, 3, 2, 4, 100]
+            .iter()
+            .map(|ns| Duration::from_nanos(*ns))
+            .collect();
+
+        let stats = Stats::from_samples(&samples);
+
+        assert_eq!(6, stats.runs);
+        assert_eq!(Duration::from_nanos(1), stats.min);
+        assert_eq!(Duration::from_nanos(3), stats.median);
+        assert_eq!(Duration::from_nanos(19), stats.mean);
+        assert_eq!(Duration::from_nanos(36), stats.stddev);
+        assert_eq!(Duration::from_nanos(100), stats.p95);
+    }
+
+    #[test]
+    fn should_run_the_requested_number_of_times() {
+        let options = BenchOptions {
+            warmup_runs: 2,
+            limit: BenchLimit::Runs(5),
+        };
+        let mut calls = 0;
+
+        let stats = benchmark(&options, || calls += 1);
+
+        assert_eq!(5, stats.runs);
+        assert_eq!(7, calls);
+    }
+
+    #[test]
+    fn should_parse_duration() {
+        assert_eq!(Duration::from_millis(500), parse_duration("500ms").unwrap());
+        assert_eq!(Duration::from_millis(1500), parse_duration("1.5s").unwrap());
+        assert!(parse_duration("10").is_err());
+        assert!(parse_duration("10h").is_err());
+        let huge = format!("1{}s", "0".repeat(30));
+        assert!(parse_duration(&huge).is_err());
+    }
+}
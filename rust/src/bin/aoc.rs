@@ -0,0 +1,566 @@
+use anyhow::*;
+use aoc2024::answers::AnswerStore;
+use aoc2024::bench::{parse_duration, BenchLimit, BenchOptions, Stats};
+use aoc2024::crosscheck::{default_python_dir, python_script, run_python_day, DEFAULT_PYTHON};
+use aoc2024::days::{find_day, registry};
+use aoc2024::history::{
+    append_history, compare, current_commit, current_timestamp, default_history_path, load_history,
+    parse_threshold, HistoryEntry,
+};
+use aoc2024::image::ImageFormat;
+use aoc2024::input::{find_data_dir, read_input, InputSource};
+use aoc2024::report::{PartReport, ReportFormat, CSV_HEADER};
+use aoc2024::runner::RegisteredDay;
+use aoc2024::scaffold::{crate_dir, examples_dir, scaffold_day, ScaffoldPaths};
+use aoc2024::simulation::{ExportOptions, DEFAULT_DELAY};
+use aoc2024::*;
+use std::fs;
+use std::path::PathBuf;
+
+const USAGE: &str = "Usage: aoc run <day|all> [options]
+       aoc compare [--threshold <percent>] [--baseline <commit>]
+       aoc crosscheck [<day|all>] [--python <command>] [--python-dir <dir>]
+       aoc new <day>
+       aoc visualize <day> [--part <1|2>] [--input <file>] [--delay <duration>] [--detector <name>]
+       aoc export <day> --output <file.ppm|png|gif> [options]
+
+Run options:
+  --part <1|2>              Only solve the given part
+  --input <file|->          Read the input from a file, or from stdin with '-'
+  --record                  Record the answers of parts without a known answer
+  --format <text|json|csv>  Output format (default: text)
+  --bench                   Benchmark each part
+  --runs <n>                Number of benchmark runs (implies --bench)
+  --budget <duration>       Time budget of each benchmark, e.g. 500ms or 2s (implies --bench)
+  --warmup <n>              Number of warmup runs before benchmarking (default: 3)
+  --detector <name>         Detector of the Christmas tree of day 14 part 2 (default: axis variance)
+
+Compare options:
+  --threshold <percent>     Slowdown of the median time flagged as a regression (default: 10%)
+  --baseline <commit>       Compare with the latest benchmark of the given commit
+                            instead of the previous one
+
+Crosscheck options:
+  --python <command>        Python interpreter, e.g. \"uv run python\" (default: python3)
+  --python-dir <dir>        Directory of the Python day scripts (default: ../python)
+
+Visualize options:
+  --part <1|2>              Simulation of the given part (default: 1)
+  --input <file>            Read the input from a file
+  --delay <duration>        Time between two ticks while playing, e.g. 50ms (default: 200ms)
+  --detector <name>         Detector of the Christmas tree of day 14 part 2 (default: axis variance)
+  Commands are read from stdin: press enter to step, 'p' to play/pause, 'q' to quit
+
+Export options:
+  --output <file>           Image to write, its extension picks the format: ppm, png or gif
+  --part <1|2>              Simulation of the given part (default: 1)
+  --input <file|->          Read the input from a file, or from stdin with '-'
+  --tick <n>                Tick of the image, or of the first frame of a GIF (default: 0)
+  --frames <n>              Maximum number of frames of a GIF (default: 100)
+  --scale <n>               Size in pixels of a tile (default: 4)
+  --delay <duration>        Time between two frames of a GIF (default: 100ms)";
+
+const DEFAULT_THRESHOLD: f64 = 0.1;
+
+struct RunArgs {
+    days: Vec<RegisteredDay>,
+    parts: Vec<Part>,
+    input: InputSource,
+    record: bool,
+    format: ReportFormat,
+    bench: Option<BenchOptions>,
+    options: DayOptions,
+}
+
+fn parse_day(value: &str) -> Result<RegisteredDay> {
+    let day_number: u8 = value
+        .parse()
+        .with_context(|| format!("Invalid day: {}", value))?;
+    let day = format!("{:0>2}", day_number);
+    find_day(&day).ok_or_else(|| anyhow!("Day {} is not solved yet", day))
+}
+
+fn parse_part(value: &str) -> Result<Part> {
+    match value {
+        "1" => Ok(Part::One),
+        "2" => Ok(Part::Two),
+        _ => bail!("Invalid part: {} (expected 1 or 2)", value),
+    }
+}
+
+fn next_value<'a>(args: &mut impl Iterator<Item = &'a String>, flag: &str) -> Result<&'a str> {
+    args.next()
+        .map(String::as_str)
+        .ok_or_else(|| anyhow!("{} needs a value", flag))
+}
+
+fn parse_run_args(args: &[String]) -> Result<RunArgs> {
+    let mut days = None;
+    let mut parts = Part::ALL.to_vec();
+    let mut input = InputSource::default();
+    let mut record = false;
+    let mut format = ReportFormat::default();
+    let mut bench: Option<BenchOptions> = None;
+    let mut options = DayOptions::default();
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" => parts = vec![parse_part(next_value(&mut args, arg)?)?],
+            "--input" => input = InputSource::from_arg(next_value(&mut args, arg)?),
+            "--record" => record = true,
+            "--format" => format = next_value(&mut args, arg)?.parse()?,
+            "--bench" => {
+                bench.get_or_insert_with(BenchOptions::default);
+            }
+            "--runs" => {
+                let runs: usize = next_value(&mut args, arg)?.parse()?;
+                if runs == 0 {
+                    bail!("--runs must be at least 1");
+                }
+                bench.get_or_insert_with(BenchOptions::default).limit = BenchLimit::Runs(runs);
+            }
+            "--budget" => {
+                let budget = parse_duration(next_value(&mut args, arg)?)?;
+                bench.get_or_insert_with(BenchOptions::default).limit = BenchLimit::Budget(budget);
+            }
+            "--warmup" => {
+                let warmup_runs = next_value(&mut args, arg)?.parse()?;
+                bench.get_or_insert_with(BenchOptions::default).warmup_runs = warmup_runs;
+            }
+            "--detector" => options.detector = Some(next_value(&mut args, arg)?.to_string()),
+            "all" => days = Some(registry()),
+            value => days = Some(vec![parse_day(value)?]),
+        }
+    }
+
+    let days = days.ok_or_else(|| anyhow!(USAGE))?;
+    if days.len() > 1 && input != InputSource::DataDir {
+        bail!("--input can only be used when running a single day");
+    }
+    if days.len() > 1 && options != DayOptions::default() {
+        bail!("--detector can only be used when running a single day");
+    }
+    if record && input == InputSource::Stdin {
+        bail!("--record can not be used with an input read from stdin");
+    }
+
+    Ok(RunArgs {
+        days,
+        parts,
+        input,
+        record,
+        format,
+        bench,
+        options,
+    })
+}
+
+fn print_stats(label: &str, stats: &Stats) {
+    println!(
+        "{}: min {} | median {} | mean {} ± {} | p95 {} ({} runs)",
+        label,
+        pretty_duration(stats.min),
+        pretty_duration(stats.median),
+        pretty_duration(stats.mean),
+        pretty_duration(stats.stddev),
+        pretty_duration(stats.p95),
+        stats.runs
+    );
+}
+
+fn print_text_report(report: &PartReport) {
+    println!(
+        "Part {}: {} (solved in {})",
+        report.part,
+        report.answer,
+        pretty_duration(report.solve_duration)
+    );
+    println!("{}", report.verdict);
+    if let Some(stats) = &report.stats {
+        print_stats("Benchmark", stats);
+    }
+}
+
+fn print_report(report: &PartReport, format: ReportFormat) {
+    match format {
+        ReportFormat::Text => print_text_report(report),
+        ReportFormat::Json => println!("{}", report.to_json()),
+        ReportFormat::Csv => println!("{}", report.to_csv()),
+    }
+}
+
+struct RunContext {
+    answers: AnswerStore,
+    recorded: bool,
+    invalid_answers: usize,
+    history: Vec<HistoryEntry>,
+    timestamp: u64,
+    commit: Option<String>,
+}
+
+fn run_day(registered: &RegisteredDay, run_args: &RunArgs, context: &mut RunContext) -> Result<()> {
+    let is_text = run_args.format == ReportFormat::Text;
+    if is_text {
+        start_day(registered.day);
+    }
+
+    let (input_data, duration) = time_function!(read_input(registered.day, &run_args.input)?);
+    if is_text {
+        println!("Input data loaded in {}", pretty_duration(duration));
+    }
+
+    let day_run = registered.run(&input_data, &run_args.parts, &run_args.options)?;
+    if is_text {
+        println!(
+            "Input data parsed in {}",
+            pretty_duration(day_run.parse_duration)
+        );
+    }
+
+    let day_bench = match &run_args.bench {
+        Some(options) => {
+            Some(registered.bench(&input_data, &run_args.parts, &run_args.options, options)?)
+        }
+        None => None,
+    };
+    if let (true, Some(day_bench)) = (is_text, &day_bench) {
+        print_stats("Parsing benchmark", &day_bench.parse_stats);
+    }
+
+    let input_name = run_args.input.name(registered.day);
+    for part_run in day_run.parts {
+        let expected = input_name
+            .as_deref()
+            .and_then(|input_name| {
+                context
+                    .answers
+                    .get(registered.day, input_name, part_run.part)
+            })
+            .map(str::to_string);
+        let stats = day_bench.as_ref().and_then(|day_bench| {
+            day_bench
+                .parts
+                .iter()
+                .find(|part_bench| part_bench.part == part_run.part)
+                .map(|part_bench| part_bench.stats)
+        });
+
+        let report = PartReport {
+            day: registered.day.to_string(),
+            part: part_run.part,
+            verdict: Verdict::new(&part_run.answer, expected.as_deref()),
+            answer: part_run.answer,
+            expected,
+            parse_duration: day_run.parse_duration,
+            solve_duration: part_run.duration,
+            stats,
+        };
+        print_report(&report, run_args.format);
+        if report.verdict.is_invalid() {
+            context.invalid_answers += 1;
+        }
+
+        if let Some(input_name) = &input_name {
+            if let Some(stats) = &report.stats {
+                context.history.push(HistoryEntry::new(
+                    context.timestamp,
+                    context.commit.clone(),
+                    registered.day,
+                    report.part,
+                    input_name,
+                    stats,
+                ));
+            }
+            if run_args.record && report.expected.is_none() {
+                context
+                    .answers
+                    .record(registered.day, input_name, report.part, &report.answer);
+                context.recorded = true;
+                if is_text {
+                    println!("📝 Answer recorded for {}", input_name);
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn run(args: &[String]) -> Result<()> {
+    let run_args = parse_run_args(args)?;
+
+    let answers_path = AnswerStore::default_path();
+    let answers = match &answers_path {
+        Result::Ok(path) => AnswerStore::load(path)?,
+        Err(_) => AnswerStore::default(),
+    };
+    let mut context = RunContext {
+        answers,
+        recorded: false,
+        invalid_answers: 0,
+        history: Vec::new(),
+        timestamp: current_timestamp(),
+        commit: run_args.bench.and_then(|_| current_commit()),
+    };
+
+    if run_args.format == ReportFormat::Csv {
+        println!("{}", CSV_HEADER);
+    }
+
+    for (i, registered) in run_args.days.iter().enumerate() {
+        if i > 0 && run_args.format == ReportFormat::Text {
+            println!();
+        }
+        run_day(registered, &run_args, &mut context)?;
+    }
+
+    if context.recorded {
+        context.answers.save(&answers_path?)?;
+    }
+    if !context.history.is_empty() {
+        append_history(&default_history_path()?, &context.history)?;
+    }
+    if context.invalid_answers > 0 {
+        bail!("{} answer(s) are invalid", context.invalid_answers);
+    }
+
+    Ok(())
+}
+
+fn compare_history(args: &[String]) -> Result<()> {
+    let mut threshold = DEFAULT_THRESHOLD;
+    let mut baseline = None;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--threshold" => threshold = parse_threshold(next_value(&mut args, arg)?)?,
+            "--baseline" => baseline = Some(next_value(&mut args, arg)?),
+            _ => bail!(USAGE),
+        }
+    }
+
+    let history_path = default_history_path()?;
+    let history = load_history(&history_path)?;
+    let comparisons = compare(&history, baseline);
+    if comparisons.is_empty() {
+        println!(
+            "Nothing to compare in {}, run some benchmarks with --bench first",
+            history_path.display()
+        );
+        return Ok(());
+    }
+
+    let mut regressions = 0;
+    for comparison in &comparisons {
+        let is_regression = comparison.is_regression(threshold);
+        if is_regression {
+            regressions += 1;
+        }
+        println!(
+            "{} Day {} part {} ({}): {} -> {} ({:+.1}%) [{} -> {}]",
+            if is_regression { "❌" } else { "✅" },
+            comparison.latest.day,
+            comparison.latest.part,
+            comparison.latest.input_name,
+            pretty_duration(comparison.baseline.median),
+            pretty_duration(comparison.latest.median),
+            comparison.change() * 100.0,
+            comparison.baseline.commit.as_deref().unwrap_or("unknown"),
+            comparison.latest.commit.as_deref().unwrap_or("unknown"),
+        );
+    }
+
+    if regressions > 0 {
+        bail!(
+            "{} part(s) got slower by more than {:.1}%",
+            regressions,
+            threshold * 100.0
+        );
+    }
+    Ok(())
+}
+
+fn crosscheck(args: &[String]) -> Result<()> {
+    let mut days = registry();
+    let mut python = DEFAULT_PYTHON.to_string();
+    let mut python_dir = default_python_dir();
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--python" => python = next_value(&mut args, arg)?.to_string(),
+            "--python-dir" => python_dir = next_value(&mut args, arg)?.into(),
+            "all" => days = registry(),
+            value => {
+                let registered = parse_day(value)?;
+                if !python_script(&python_dir, registered.day).exists() {
+                    bail!("Day {} has no Python version", registered.day);
+                }
+                days = vec![registered];
+            }
+        }
+    }
+
+    let data_dir = find_data_dir()?;
+    let mut mismatches = 0;
+    for registered in days {
+        let script = python_script(&python_dir, registered.day);
+        if !script.exists() {
+            continue;
+        }
+
+        let input_data = read_input(registered.day, &InputSource::DataDir)?;
+        let day_run = registered.run(&input_data, &Part::ALL, &DayOptions::default())?;
+        let python_answers = run_python_day(&python, &script, &data_dir)?;
+
+        for part_run in day_run.parts {
+            let python_answer = python_answers
+                .iter()
+                .find(|python_answer| python_answer.part == part_run.part);
+            let (status, python_result) = match python_answer {
+                Some(python_answer) => {
+                    let status = if python_answer.answer == part_run.answer {
+                        "✅"
+                    } else {
+                        mismatches += 1;
+                        "❌"
+                    };
+                    let duration = python_answer
+                        .duration
+                        .map(pretty_duration)
+                        .unwrap_or_else(|| "?".to_string());
+                    (status, format!("{} ({})", python_answer.answer, duration))
+                }
+                None => ("⚠️", "no answer".to_string()),
+            };
+            println!(
+                "{} Day {} part {}: rust {} ({}) | python {}",
+                status,
+                registered.day,
+                part_run.part,
+                part_run.answer,
+                pretty_duration(part_run.duration),
+                python_result
+            );
+        }
+    }
+
+    if mismatches > 0 {
+        bail!("{} answer(s) differ between Rust and Python", mismatches);
+    }
+    Ok(())
+}
+
+fn new_day(args: &[String]) -> Result<()> {
+    let [value] = args else {
+        bail!(USAGE);
+    };
+    let day_number: u8 = value
+        .parse()
+        .with_context(|| format!("Invalid day: {}", value))?;
+    if !(1..=25).contains(&day_number) {
+        bail!("Invalid day: {} (expected 1 to 25)", value);
+    }
+    let day = format!("{:0>2}", day_number);
+
+    let paths = ScaffoldPaths {
+        crate_dir: crate_dir(),
+        examples_dir: examples_dir(),
+        data_dir: find_data_dir().ok(),
+    };
+    for path in scaffold_day(&day, &paths)? {
+        println!("Created {}", path.display());
+    }
+    println!("Registered day {} in src/days/mod.rs", day);
+    Ok(())
+}
+
+fn visualize(args: &[String]) -> Result<()> {
+    let mut day = None;
+    let mut part = Part::One;
+    let mut input = InputSource::default();
+    let mut delay = DEFAULT_DELAY;
+    let mut options = DayOptions::default();
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" => part = parse_part(next_value(&mut args, arg)?)?,
+            "--input" => input = InputSource::from_arg(next_value(&mut args, arg)?),
+            "--delay" => delay = parse_duration(next_value(&mut args, arg)?)?,
+            "--detector" => options.detector = Some(next_value(&mut args, arg)?.to_string()),
+            value => day = Some(parse_day(value)?),
+        }
+    }
+
+    let registered = day.ok_or_else(|| anyhow!(USAGE))?;
+    if input == InputSource::Stdin {
+        bail!("The input can not be read from stdin, it is used for the visualizer commands");
+    }
+    let input_data = read_input(registered.day, &input)?;
+    registered.visualize(&input_data, part, &options, delay)
+}
+
+fn export_images(args: &[String]) -> Result<()> {
+    let mut day = None;
+    let mut part = Part::One;
+    let mut input = InputSource::default();
+    let mut output = None;
+    let mut tick = None;
+    let mut frames = None;
+    let mut scale = None;
+    let mut delay = None;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--output" => output = Some(PathBuf::from(next_value(&mut args, arg)?)),
+            "--part" => part = parse_part(next_value(&mut args, arg)?)?,
+            "--input" => input = InputSource::from_arg(next_value(&mut args, arg)?),
+            "--tick" => tick = Some(next_value(&mut args, arg)?.parse()?),
+            "--frames" => frames = Some(next_value(&mut args, arg)?.parse()?),
+            "--scale" => scale = Some(next_value(&mut args, arg)?.parse()?),
+            "--delay" => delay = Some(parse_duration(next_value(&mut args, arg)?)?),
+            value => day = Some(parse_day(value)?),
+        }
+    }
+
+    let (Some(registered), Some(output)) = (day, output) else {
+        bail!(USAGE);
+    };
+    let defaults = ExportOptions::new(ImageFormat::from_path(&output)?);
+    let options = ExportOptions {
+        tick: tick.unwrap_or(defaults.tick),
+        frames: frames.unwrap_or(defaults.frames),
+        scale: scale.unwrap_or(defaults.scale),
+        delay: delay.unwrap_or(defaults.delay),
+        ..defaults
+    };
+
+    let input_data = read_input(registered.day, &input)?;
+    let image = registered.export(&input_data, part, &options)?;
+    fs::write(&output, image).with_context(|| format!("Could not write {}", output.display()))?;
+    println!(
+        "Exported day {} part {} to {}",
+        registered.day,
+        part,
+        output.display()
+    );
+    Ok(())
+}
+
+fn main() -> Result<()> {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    match args.first().map(String::as_str) {
+        Some("run") => run(&args[1..]),
+        Some("compare") => compare_history(&args[1..]),
+        Some("crosscheck") => crosscheck(&args[1..]),
+        Some("new") => new_day(&args[1..]),
+        Some("visualize") => visualize(&args[1..]),
+        Some("export") => export_images(&args[1..]),
+        _ => bail!(USAGE),
+    }
+}
@@ -0,0 +1,134 @@
+use crate::bench::parse_duration;
+use crate::input::DATA_DIR_ENV;
+use crate::Part;
+use anyhow::{bail, Context, Result};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::Duration;
+
+pub const DEFAULT_PYTHON: &str = "python3";
+
+#[derive(Debug, PartialEq)]
+pub struct PythonAnswer {
+    pub part: Part,
+    pub answer: String,
+    pub duration: Option<Duration>,
+}
+
+pub fn default_python_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("../python")
+}
+
+pub fn python_script(python_dir: &Path, day: &str) -> PathBuf {
+    python_dir.join(format!("day-{}.py", day))
+}
+
+// Parses "12.345 μs" as printed by pretty_duration_ns in python/common.py
+fn parse_python_duration(value: &str) -> Option<Duration> {
+    let value = value.replace('μ', "u").replace(' ', "");
+    parse_duration(&value).ok()
+}
+
+// Parses the "Part N: <answer> (solved in <duration>)" lines of a Python day script
+pub fn parse_python_output(output: &str) -> Vec<PythonAnswer> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let rest = line.trim().strip_prefix("Part ")?;
+            let (part, rest) = rest.split_once(": ")?;
+            let part = match part {
+                "1" => Part::One,
+                "2" => Part::Two,
+                _ => return None,
+            };
+
+            let (answer, duration) = match rest.rsplit_once(" (solved in ") {
+                Some((answer, duration)) => (
+                    answer,
+                    duration.strip_suffix(')').and_then(parse_python_duration),
+                ),
+                None => (rest, None),
+            };
+
+            Some(PythonAnswer {
+                part,
+                answer: answer.trim().to_string(),
+                duration,
+            })
+        })
+        .collect()
+}
+
+// Runs a Python day script on the inputs of the given data directory
+pub fn run_python_day(python: &str, script: &Path, data_dir: &Path) -> Result<Vec<PythonAnswer>> {
+    let mut command_line = python.split_whitespace();
+    let Some(program) = command_line.next() else {
+        bail!("Empty python command");
+    };
+    let python_dir = script.parent().unwrap_or(Path::new("."));
+
+    let output = Command::new(program)
+        .args(command_line)
+        .arg(script)
+        .current_dir(python_dir)
+        .env(DATA_DIR_ENV, data_dir)
+        .output()
+        .with_context(|| format!("Could not run {} {}", python, script.display()))?;
+
+    if !output.status.success() {
+        bail!(
+            "{} failed ({}): {}",
+            script.display(),
+            output.status,
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+
+    Ok(parse_python_output(&String::from_utf8_lossy(
+        &output.stdout,
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_parse_python_output() {
+        let output = "Input data loaded in 31.250 μs
+Part 1: 213 (solved in 1.204 ms)
+✅ Solution is valid!
+Part 2: 285 (solved in 812.000 ns)
+⚠️ No known solution... Can not check
+";
+
+        assert_eq!(
+            vec![
+                PythonAnswer {
+                    part: Part::One,
+                    answer: "213".to_string(),
+                    duration: Some(Duration::from_micros(1204)),
+                },
+                PythonAnswer {
+                    part: Part::Two,
+                    answer: "285".to_string(),
+                    duration: Some(Duration::from_nanos(812)),
+                },
+            ],
+            parse_python_output(output)
+        );
+    }
+
+    #[test]
+    fn should_parse_python_duration() {
+        assert_eq!(
+            Some(Duration::from_nanos(31250)),
+            parse_python_duration("31.250 μs")
+        );
+        assert_eq!(
+            Some(Duration::from_secs(2)),
+            parse_python_duration("2.000 s")
+        );
+        assert_eq!(None, parse_python_duration("soon"));
+    }
+}
@@ -0,0 +1,73 @@
+use crate::parse::{input_lines, ParseError};
+use crate::Solution;
+use anyhow::*;
+use std::collections::HashMap;
+
+const DAY: &str = "01";
+
+fn input_to_vecs(input: &str) -> Result<(Vec<u32>, Vec<u32>), ParseError> {
+    let mut left = Vec::<u32>::new();
+    let mut right = Vec::<u32>::new();
+
+    for line in input_lines(DAY, input) {
+        if !line.is_blank() {
+            let mut elems = line.text.split_whitespace();
+            left.push(line.parse(line.next_field(&mut elems, "left location ID")?)?);
+            right.push(line.parse(line.next_field(&mut elems, "right location ID")?)?);
+            line.expect_end(&mut elems)?;
+        }
+    }
+
+    left.sort();
+    right.sort();
+
+    Result::Ok((left, right))
+}
+
+//region Part 1
+
+fn solve_part_1((left, right): &(Vec<u32>, Vec<u32>)) -> Result<String> {
+    let mut total = 0;
+    for i in 0..left.len() {
+        total += left[i].abs_diff(right[i]);
+    }
+    Ok(format!("{}", total))
+}
+//endregion
+
+//region Part 2
+
+fn solve_part_2((left, right): &(Vec<u32>, Vec<u32>)) -> Result<String> {
+    let mut presents_in_right = HashMap::<u32, u32>::new();
+    for elem in right.iter() {
+        let tmp = presents_in_right.get(elem).unwrap_or(&0);
+        presents_in_right.insert(*elem, *tmp + 1);
+    }
+
+    let mut result = 0;
+    for elem in left {
+        result += elem * presents_in_right.get(elem).unwrap_or(&0);
+    }
+    Ok(format!("{}", result))
+}
+//endregion
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: &'static str = DAY;
+
+    type Input = (Vec<u32>, Vec<u32>);
+
+    fn parse_input(input_data: &str) -> Result<Self::Input> {
+        Ok(input_to_vecs(input_data)?)
+    }
+
+    fn solve_part_1(lists: &Self::Input) -> Result<String> {
+        solve_part_1(lists)
+    }
+
+    fn solve_part_2(lists: &Self::Input) -> Result<String> {
+        solve_part_2(lists)
+    }
+}
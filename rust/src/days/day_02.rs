@@ -0,0 +1,128 @@
+use crate::parse::{input_lines, ParseError};
+use crate::Solution;
+use anyhow::*;
+
+const DAY: &str = "02";
+
+fn process_input(input: &str) -> Result<Vec<Vec<i32>>, ParseError> {
+    input_lines(DAY, input)
+        .filter(|line| !line.is_blank())
+        .map(|line| {
+            line.text
+                .split_whitespace()
+                .map(|level| line.parse::<i32>(level))
+                .collect()
+        })
+        .collect()
+}
+
+fn is_report_safe(report: &[i32]) -> bool {
+    let ascending = report.first() <= report.last();
+
+    report.windows(2).all(|pair| {
+        let increment = pair[1] - pair[0];
+        match ascending {
+            true => (1..=3).contains(&increment),
+            false => (-3..=-1).contains(&increment),
+        }
+    })
+}
+
+fn is_fixable_report(report: &[i32]) -> bool {
+    (0..report.len()).any(|i| {
+        let mut truncated_report = report.to_vec();
+        truncated_report.remove(i);
+        is_report_safe(&truncated_report)
+    })
+}
+
+//region Part 1
+
+fn solve_part_1(reports: &[Vec<i32>]) -> Result<String> {
+    let n_safe = reports
+        .iter()
+        .filter(|report| is_report_safe(report))
+        .count();
+    Ok(format!("{}", n_safe))
+}
+//endregion
+
+//region Part 2
+
+fn solve_part_2(reports: &[Vec<i32>]) -> Result<String> {
+    let n_safe = reports
+        .iter()
+        .filter(|report| is_report_safe(report) || is_fixable_report(report))
+        .count();
+    Ok(format!("{}", n_safe))
+}
+//endregion
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: &'static str = DAY;
+
+    type Input = Vec<Vec<i32>>;
+
+    fn parse_input(input_data: &str) -> Result<Self::Input> {
+        Ok(process_input(input_data)?)
+    }
+
+    fn solve_part_1(reports: &Self::Input) -> Result<String> {
+        solve_part_1(reports)
+    }
+
+    fn solve_part_2(reports: &Self::Input) -> Result<String> {
+        solve_part_2(reports)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "7 6 4 2 1
+1 2 7 8 9
+9 7 6 2 1
+1 3 2 4 5
+8 6 4 4 1
+1 3 6 7 9";
+
+    #[test]
+    fn should_check_report_safety() {
+        // Given the example reports
+        let reports = process_input(EXAMPLE).unwrap();
+
+        // When checking whether each report is safe
+        let safety: Vec<bool> = reports.iter().map(|r| is_report_safe(r)).collect();
+
+        // Then only the first and the last ones should be
+        assert_eq!(vec![true, false, false, false, false, true], safety);
+    }
+
+    #[test]
+    fn should_fix_reports_by_removing_one_level() {
+        // Given the example reports
+        let reports = process_input(EXAMPLE).unwrap();
+
+        // When checking whether each report can be fixed
+        let fixable: Vec<bool> = reports.iter().map(|r| is_fixable_report(r)).collect();
+
+        // Then the 4th and 5th should now be safe as well
+        assert_eq!(vec![true, false, false, true, true, true], fixable);
+    }
+
+    #[test]
+    fn should_solve_example() {
+        let reports = process_input(EXAMPLE).unwrap();
+
+        assert_eq!("2", solve_part_1(&reports).unwrap());
+        assert_eq!("4", solve_part_2(&reports).unwrap());
+    }
+
+    #[test]
+    fn should_reject_invalid_levels() {
+        assert!(process_input("1 2 x").is_err());
+    }
+}
@@ -0,0 +1,145 @@
+use crate::Solution;
+use anyhow::*;
+
+const DAY: &str = "03";
+
+const MUL_INSTRUCTION: &str = "mul(";
+const DO_INSTRUCTION: &str = "do()";
+const DONT_INSTRUCTION: &str = "don't()";
+
+// Parses "mul(X,Y)" at the start of the memory, returning the product and the length read
+fn parse_multiplication(memory: &str) -> Option<(usize, usize)> {
+    let arguments = memory.strip_prefix(MUL_INSTRUCTION)?;
+    let end = arguments.find(')')?;
+    let (left, right) = arguments[..end].split_once(',')?;
+
+    let is_number =
+        |value: &str| (1..=3).contains(&value.len()) && value.chars().all(|c| c.is_ascii_digit());
+    if !is_number(left) || !is_number(right) {
+        return None;
+    }
+
+    let result = left.parse::<usize>().ok()? * right.parse::<usize>().ok()?;
+    Some((result, MUL_INSTRUCTION.len() + end + 1))
+}
+
+fn parse_multiplications(memory: &str) -> Vec<usize> {
+    let mut multiplications = Vec::new();
+
+    let mut start = 0;
+    while let Some(offset) = memory[start..].find(MUL_INSTRUCTION) {
+        let position = start + offset;
+        match parse_multiplication(&memory[position..]) {
+            Some((result, length)) => {
+                multiplications.push(result);
+                start = position + length;
+            }
+            None => start = position + MUL_INSTRUCTION.len(),
+        }
+    }
+
+    multiplications
+}
+
+// Multiplications found between a do() (or the start) and the next don't()
+fn parse_enabled_multiplications(memory: &str) -> Vec<usize> {
+    let mut multiplications = Vec::new();
+
+    let mut remaining = memory;
+    loop {
+        let (enabled, rest) = match remaining.split_once(DONT_INSTRUCTION) {
+            Some((enabled, rest)) => (enabled, Some(rest)),
+            None => (remaining, None),
+        };
+        multiplications.append(&mut parse_multiplications(enabled));
+
+        match rest.and_then(|rest| rest.split_once(DO_INSTRUCTION)) {
+            Some((_, next)) => remaining = next,
+            None => break,
+        }
+    }
+
+    multiplications
+}
+
+//region Part 1
+
+fn solve_part_1(input_data: &str) -> Result<String> {
+    Ok(format!(
+        "{}",
+        parse_multiplications(input_data).iter().sum::<usize>()
+    ))
+}
+//endregion
+
+//region Part 2
+
+fn solve_part_2(input_data: &str) -> Result<String> {
+    Ok(format!(
+        "{}",
+        parse_enabled_multiplications(input_data)
+            .iter()
+            .sum::<usize>()
+    ))
+}
+//endregion
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: &'static str = DAY;
+
+    type Input = String;
+
+    fn parse_input(input_data: &str) -> Result<Self::Input> {
+        Ok(input_data.to_string())
+    }
+
+    fn solve_part_1(input_data: &Self::Input) -> Result<String> {
+        solve_part_1(input_data)
+    }
+
+    fn solve_part_2(input_data: &Self::Input) -> Result<String> {
+        solve_part_2(input_data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_parse_multiplication() {
+        assert_eq!(Some((8, 8)), parse_multiplication("mul(2,4)%&mul[3,7]"));
+        assert_eq!(Some((123 * 4, 10)), parse_multiplication("mul(123,4)"));
+        assert_eq!(None, parse_multiplication("mul(4*"));
+        assert_eq!(None, parse_multiplication("mul(6,9!"));
+        assert_eq!(None, parse_multiplication("mul ( 2 , 4 )"));
+    }
+
+    #[test]
+    fn should_parse_multiplications() {
+        // Given some corrupted memory
+        let memory = "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))";
+
+        // When parsing the multiplications
+        let multiplications = parse_multiplications(memory);
+
+        // Then only the valid ones should be found
+        assert_eq!(vec![8, 25, 88, 40], multiplications);
+        assert_eq!("161", solve_part_1(memory).unwrap());
+    }
+
+    #[test]
+    fn should_only_parse_enabled_multiplications() {
+        // Given some corrupted memory with conditional instructions
+        let memory = "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+
+        // When parsing the enabled multiplications
+        let multiplications = parse_enabled_multiplications(memory);
+
+        // Then the ones between don't() and do() should be skipped
+        assert_eq!(vec![8, 40], multiplications);
+        assert_eq!("48", solve_part_2(memory).unwrap());
+    }
+}
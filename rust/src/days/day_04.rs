@@ -0,0 +1,144 @@
+use crate::grid::Grid;
+use crate::position::Position;
+use crate::Solution;
+use anyhow::*;
+
+const DAY: &str = "04";
+
+const WORD: [char; 4] = ['X', 'M', 'A', 'S'];
+
+// Offsets of the 8 directions a word can be written in
+fn directions() -> [Position; 8] {
+    Position::default().neighbours_8()
+}
+
+fn is_word_in_direction(grid: &Grid<char>, start: Position, direction: Position) -> bool {
+    let mut position = start;
+    for letter in WORD {
+        if grid.get(position) != Some(&letter) {
+            return false;
+        }
+        position += direction;
+    }
+    true
+}
+
+fn count_words_from_position(grid: &Grid<char>, start: Position) -> usize {
+    directions()
+        .into_iter()
+        .filter(|direction| is_word_in_direction(grid, start, *direction))
+        .count()
+}
+
+// Both diagonals through the position spell MAS, forwards or backwards
+fn is_cross(grid: &Grid<char>, center: Position) -> bool {
+    if grid.get(center) != Some(&'A') {
+        return false;
+    }
+
+    let is_mas_diagonal = |offset: Position| {
+        let ends = (grid.get(center - offset), grid.get(center + offset));
+        matches!(ends, (Some('M'), Some('S')) | (Some('S'), Some('M')))
+    };
+    is_mas_diagonal(Position::new(1, 1)) && is_mas_diagonal(Position::new(1, -1))
+}
+
+//region Part 1
+
+fn solve_part_1(grid: &Grid<char>) -> Result<String> {
+    let n_found: usize = grid
+        .find_all(|letter| *letter == WORD[0])
+        .into_iter()
+        .map(|position| count_words_from_position(grid, position))
+        .sum();
+    Ok(format!("{}", n_found))
+}
+//endregion
+
+//region Part 2
+
+fn solve_part_2(grid: &Grid<char>) -> Result<String> {
+    let n_found = grid
+        .positions()
+        .filter(|position| is_cross(grid, *position))
+        .count();
+    Ok(format!("{}", n_found))
+}
+//endregion
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: &'static str = DAY;
+
+    type Input = Grid<char>;
+
+    fn parse_input(input_data: &str) -> Result<Self::Input> {
+        Ok(input_data
+            .parse::<Grid<char>>()
+            .map_err(|error| error.with_day(DAY))?)
+    }
+
+    fn solve_part_1(grid: &Self::Input) -> Result<String> {
+        solve_part_1(grid)
+    }
+
+    fn solve_part_2(grid: &Self::Input) -> Result<String> {
+        solve_part_2(grid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "MMMSXXMASM
+MSAMXMSMSA
+AMXSXMAAMM
+MSAMASMSMX
+XMASAMXAMM
+XXAMMXXAMA
+SMSMSASXSS
+SAXAMASAAA
+MAMMMXMMMM
+MXMXAXMASX";
+
+    #[test]
+    fn should_count_words_in_every_direction() {
+        // Given a small grid with XMAS written forwards, backwards and diagonally
+        let grid: Grid<char> = "..X...
+.SAMX.
+.A..A.
+XMAS.S
+.X....
+"
+        .parse()
+        .unwrap();
+
+        // When counting the words
+        let result = solve_part_1(&grid).unwrap();
+
+        // Then all of them should be found
+        assert_eq!("4", result);
+    }
+
+    #[test]
+    fn should_solve_example() {
+        let grid: Grid<char> = EXAMPLE.parse().unwrap();
+
+        assert_eq!("18", solve_part_1(&grid).unwrap());
+        assert_eq!("9", solve_part_2(&grid).unwrap());
+    }
+
+    #[test]
+    fn should_detect_crosses() {
+        let grid: Grid<char> = "M.S
+.A.
+M.S"
+        .parse()
+        .unwrap();
+
+        assert!(is_cross(&grid, Position::new(1, 1)));
+        assert!(!is_cross(&grid, Position::new(0, 0)));
+    }
+}
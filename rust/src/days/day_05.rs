@@ -0,0 +1,231 @@
+use crate::parse::{input_lines, ParseError};
+use crate::Solution;
+use anyhow::*;
+use std::collections::{HashMap, HashSet};
+
+const DAY: &str = "05";
+
+pub struct PrintQueue {
+    // Page -> pages that must be printed after it
+    orders: HashMap<u32, HashSet<u32>>,
+    updates: Vec<Vec<u32>>,
+}
+
+fn process_input(input: &str) -> Result<PrintQueue, ParseError> {
+    let mut orders: HashMap<u32, HashSet<u32>> = HashMap::new();
+    let mut updates = Vec::new();
+
+    for line in input_lines(DAY, input) {
+        let text = line.text.trim();
+        if text.is_empty() {
+            continue;
+        }
+
+        if let Some((left, right)) = text.split_once('|') {
+            let left = line.parse(left)?;
+            let right = line.parse(right)?;
+            orders.entry(left).or_default().insert(right);
+        } else {
+            let update = text
+                .split(',')
+                .map(|page| line.parse(page))
+                .collect::<Result<Vec<u32>, _>>()?;
+            updates.push(update);
+        }
+    }
+
+    Result::Ok(PrintQueue { orders, updates })
+}
+
+impl PrintQueue {
+    fn must_precede(&self, page: u32, other: u32) -> bool {
+        self.orders
+            .get(&page)
+            .is_some_and(|following| following.contains(&other))
+    }
+
+    fn is_order_correct(&self, update: &[u32]) -> bool {
+        update.iter().enumerate().all(|(i, page)| {
+            update[..i]
+                .iter()
+                .all(|preceding| !self.must_precede(*page, *preceding))
+        })
+    }
+
+    // Topological sort over the rules between the pages of the update. Pages without a rule
+    // between them are not comparable, the first one of the update that is free to go comes first
+    fn reorder_update(&self, update: &[u32]) -> Result<Vec<u32>> {
+        let mut preceding_counts: Vec<usize> = update
+            .iter()
+            .map(|page| {
+                update
+                    .iter()
+                    .filter(|other| self.must_precede(**other, *page))
+                    .count()
+            })
+            .collect();
+        let mut placed = vec![false; update.len()];
+
+        let mut reordered_update = Vec::with_capacity(update.len());
+        while reordered_update.len() < update.len() {
+            let Some(next) = (0..update.len()).find(|i| !placed[*i] && preceding_counts[*i] == 0)
+            else {
+                bail!("The rules between the pages of {:?} have a cycle", update);
+            };
+            placed[next] = true;
+            reordered_update.push(update[next]);
+            for (i, page) in update.iter().enumerate() {
+                if self.must_precede(update[next], *page) {
+                    preceding_counts[i] -= 1;
+                }
+            }
+        }
+        Ok(reordered_update)
+    }
+}
+
+fn middle_page(update: &[u32]) -> u32 {
+    update[update.len() / 2]
+}
+
+//region Part 1
+
+fn solve_part_1(queue: &PrintQueue) -> Result<String> {
+    let total: u32 = queue
+        .updates
+        .iter()
+        .filter(|update| queue.is_order_correct(update))
+        .map(|update| middle_page(update))
+        .sum();
+    Ok(format!("{}", total))
+}
+//endregion
+
+//region Part 2
+
+fn solve_part_2(queue: &PrintQueue) -> Result<String> {
+    let mut total = 0;
+    for update in queue.updates.iter() {
+        if !queue.is_order_correct(update) {
+            total += middle_page(&queue.reorder_update(update)?);
+        }
+    }
+    Ok(format!("{}", total))
+}
+//endregion
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: &'static str = DAY;
+
+    type Input = PrintQueue;
+
+    fn parse_input(input_data: &str) -> Result<Self::Input> {
+        Ok(process_input(input_data)?)
+    }
+
+    fn solve_part_1(queue: &Self::Input) -> Result<String> {
+        solve_part_1(queue)
+    }
+
+    fn solve_part_2(queue: &Self::Input) -> Result<String> {
+        solve_part_2(queue)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "47|53
+97|13
+97|61
+97|47
+75|29
+61|13
+75|53
+29|13
+97|29
+53|29
+61|53
+97|53
+61|29
+47|13
+75|47
+97|75
+47|61
+75|61
+47|29
+75|13
+53|13
+
+75,47,61,53,29
+97,61,53,29,13
+75,29,13
+75,97,47,61,53
+61,13,29
+97,13,75,29,47";
+
+    #[test]
+    fn should_check_update_order() {
+        // Given the example rules and updates
+        let queue = process_input(EXAMPLE).unwrap();
+
+        // When checking each update
+        let correct: Vec<bool> = queue
+            .updates
+            .iter()
+            .map(|update| queue.is_order_correct(update))
+            .collect();
+
+        // Then only the first three should be in the right order
+        assert_eq!(vec![true, true, true, false, false, false], correct);
+    }
+
+    #[test]
+    fn should_reorder_updates() {
+        let queue = process_input(EXAMPLE).unwrap();
+
+        assert_eq!(
+            vec![97, 75, 47, 61, 53],
+            queue.reorder_update(&[75, 97, 47, 61, 53]).unwrap()
+        );
+        assert_eq!(
+            vec![61, 29, 13],
+            queue.reorder_update(&[61, 13, 29]).unwrap()
+        );
+        assert_eq!(
+            vec![97, 75, 47, 29, 13],
+            queue.reorder_update(&[97, 13, 75, 29, 47]).unwrap()
+        );
+    }
+
+    #[test]
+    fn should_reorder_updates_with_missing_rules() {
+        // Given rules that don't relate every pair of pages, nor 1 with 2
+        let queue = process_input("3|1\n2|4\n4|1\n\n1,2,3,4").unwrap();
+
+        // When reordering an update
+        let reordered = queue.reorder_update(&queue.updates[0]).unwrap();
+
+        // Then every rule should hold, and unrelated pages keep their order in the update
+        assert!(queue.is_order_correct(&reordered));
+        assert_eq!(vec![2, 3, 4, 1], reordered);
+    }
+
+    #[test]
+    fn should_reject_rules_with_a_cycle() {
+        let queue = process_input("1|2\n2|3\n3|1\n\n1,2,3").unwrap();
+
+        assert!(queue.reorder_update(&queue.updates[0]).is_err());
+    }
+
+    #[test]
+    fn should_solve_example() {
+        let queue = process_input(EXAMPLE).unwrap();
+
+        assert_eq!("143", solve_part_1(&queue).unwrap());
+        assert_eq!("123", solve_part_2(&queue).unwrap());
+    }
+}
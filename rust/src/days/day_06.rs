@@ -0,0 +1,1280 @@
+use crate::grid::Grid;
+use crate::image::Palette;
+use crate::parse::{input_lines, parse_grid, ParseError};
+use crate::position::{Direction, Position};
+use crate::simulation::Simulation;
+use crate::{Part, Solution};
+use anyhow::*;
+use std::cmp::PartialEq;
+use std::collections::{HashMap, HashSet};
+use std::num::NonZeroUsize;
+use std::thread;
+
+const DAY: &str = "06";
+
+#[derive(Default, Debug, Copy, Clone, PartialEq)]
+enum GuardPatrolStatus {
+    #[default]
+    NotStarted,
+    OnGoing,
+    Finished,
+    StuckInLoop,
+}
+
+#[derive(Default, Debug, Copy, Clone)]
+enum GuardMoveOutcome {
+    #[default]
+    Blocked,
+    Exited,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct GuardStop {
+    pub pos: Position,
+    pub direction: Direction,
+}
+
+#[derive(Debug, Default, PartialEq, Clone)]
+struct Guard {
+    position: Position,
+    initial_position: Position,
+    direction: Direction,
+    initial_direction: Direction,
+    trail: Vec<Position>,
+}
+
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+enum Tile {
+    #[default]
+    Empty,
+    Obstacle,
+    // Put there on purpose, drawn as an 'O' like the obstructions of the puzzle statement
+    Obstruction,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+struct Map {
+    grid: Grid<Tile>,
+}
+
+impl Map {
+    fn is_position_an_obstacle(&self, position: &Position) -> bool {
+        matches!(
+            self.grid.get(*position),
+            Some(Tile::Obstacle | Tile::Obstruction)
+        )
+    }
+
+    fn add_obstacle(&mut self, position: &Position) {
+        self.grid[*position] = Tile::Obstruction;
+    }
+
+    fn state_of(&self, position: &Position, direction: Direction) -> usize {
+        let index = self
+            .grid
+            .index_of(*position)
+            .expect("a position of the map");
+        index * 4 + direction as usize
+    }
+
+    fn tiles(&self) -> Grid<char> {
+        self.grid.map(|tile| match tile {
+            Tile::Empty => '.',
+            Tile::Obstacle => '#',
+            Tile::Obstruction => 'O',
+        })
+    }
+}
+
+// Set of (position, direction) states of a map, one bit each
+#[derive(Debug, PartialEq, Clone)]
+struct StateSet {
+    bits: Vec<u64>,
+}
+
+impl StateSet {
+    fn new(map: &Map) -> Self {
+        Self {
+            bits: vec![0; (map.grid.width() * map.grid.height() * 4).div_ceil(64)],
+        }
+    }
+
+    // Returns false when the state was already in the set
+    fn insert(&mut self, state: usize) -> bool {
+        let (word, bit) = (state / 64, 1u64 << (state % 64));
+        let is_new = self.bits[word] & bit == 0;
+        self.bits[word] |= bit;
+        is_new
+    }
+
+    fn clear(&mut self) {
+        self.bits.fill(0);
+    }
+}
+
+const EXIT: u32 = u32::MAX;
+
+// Where the guard stops, right before the next obstacle, when walking from any position in any
+// direction (EXIT when leaving the map)
+#[derive(Debug)]
+struct JumpTable {
+    stops: Vec<u32>,
+}
+
+impl JumpTable {
+    fn new(map: &Map) -> Self {
+        let size = map.grid.width() * map.grid.height();
+        let mut stops = vec![EXIT; size * 4];
+        for direction in Direction::ALL {
+            // The stop of the next position is needed first
+            let indices: Box<dyn Iterator<Item = usize>> = match direction {
+                Direction::Up | Direction::Left => Box::new(0..size),
+                Direction::Down | Direction::Right => Box::new((0..size).rev()),
+            };
+            for index in indices {
+                let next = map.grid.position_of(index).step(direction);
+                stops[index * 4 + direction as usize] = match map.grid.get(next) {
+                    None => EXIT,
+                    Some(Tile::Empty) => stops[map.state_of(&next, direction)],
+                    Some(_) => index as u32,
+                };
+            }
+        }
+
+        Self { stops }
+    }
+
+    // The added obstacle is patched in on lookup, so that one table serves every candidate
+    fn next_stop(
+        &self,
+        map: &Map,
+        position: Position,
+        direction: Direction,
+        added_obstacle: Option<Position>,
+    ) -> Option<Position> {
+        let stop = match self.stops[map.state_of(&position, direction)] {
+            EXIT => None,
+            index => Some(map.grid.position_of(index as usize)),
+        };
+        let Some(obstacle) = added_obstacle else {
+            return stop;
+        };
+
+        // The added obstacle only matters when it is ahead, before the stop
+        let offset = direction.offset();
+        let delta = obstacle - position;
+        let distance = delta.row * offset.row + delta.col * offset.col;
+        let is_ahead = distance > 0 && delta.row * offset.col == delta.col * offset.row;
+        match stop {
+            _ if !is_ahead => stop,
+            Some(stop) if position.manhattan_distance(stop) < distance as usize => Some(stop),
+            _ => Some(obstacle - offset),
+        }
+    }
+}
+
+// The loop a guard is stuck in
+#[derive(Debug, PartialEq, Clone)]
+pub struct Cycle {
+    // First state of the loop, which the guard keeps coming back to
+    pub entry: GuardStop,
+    pub moves: usize,
+    pub turns: usize,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct GuardPatrol {
+    guard: Guard,
+    map: Map,
+    guard_stops: Vec<GuardStop>,
+    visited: StateSet,
+    status: GuardPatrolStatus,
+}
+
+impl GuardPatrol {
+    fn new(guard: Guard, map: Map) -> Self {
+        Self {
+            guard,
+            visited: StateSet::new(&map),
+            map,
+            guard_stops: Vec::new(),
+            status: GuardPatrolStatus::NotStarted,
+        }
+    }
+
+    fn reset(&mut self) {
+        self.guard.reset();
+        self.guard_stops.clear();
+        self.visited.clear();
+        self.status = GuardPatrolStatus::NotStarted;
+    }
+
+    fn tick(&mut self) {
+        if matches!(self.status, GuardPatrolStatus::Finished)
+            || matches!(self.status, GuardPatrolStatus::StuckInLoop)
+        {
+            return;
+        }
+
+        if matches!(self.status, GuardPatrolStatus::NotStarted) {
+            self.save_guard_stop();
+            self.status = GuardPatrolStatus::OnGoing;
+        }
+
+        let move_outcome = self.guard.move_on_map(&self.map);
+
+        if !self.save_guard_stop() {
+            self.status = GuardPatrolStatus::StuckInLoop;
+            return;
+        }
+
+        if let GuardMoveOutcome::Exited = move_outcome {
+            self.status = GuardPatrolStatus::Finished;
+        }
+    }
+
+    pub fn run(&mut self) {
+        self.reset();
+        loop {
+            self.tick();
+            if self.status != GuardPatrolStatus::OnGoing {
+                break;
+            }
+        }
+    }
+
+    // Returns false when the guard already stopped there, facing the same way
+    fn save_guard_stop(&mut self) -> bool {
+        let state = self
+            .map
+            .state_of(&self.guard.position, self.guard.direction);
+        if !self.visited.insert(state) {
+            return false;
+        }
+        self.guard_stops.push(GuardStop {
+            pos: self.guard.position,
+            direction: self.guard.direction,
+        });
+        true
+    }
+
+    // Every state the guard went through, in order: a turn is a new direction on the same position
+    pub fn trail(&self) -> Vec<GuardStop> {
+        // A guard stuck in a loop walked on to the stop it had already saved
+        let ends =
+            self.guard_stops.iter().skip(1).map(|stop| stop.pos).chain(
+                (self.status == GuardPatrolStatus::StuckInLoop).then_some(self.guard.position),
+            );
+
+        let mut trail = Vec::new();
+        for (stop, end) in self.guard_stops.iter().zip(ends) {
+            let mut pos = stop.pos;
+            while pos != end {
+                trail.push(GuardStop {
+                    pos,
+                    direction: stop.direction,
+                });
+                pos = pos.step(stop.direction);
+            }
+            trail.push(GuardStop {
+                pos: end,
+                direction: stop.direction,
+            });
+        }
+        // The last stop is only missing when the guard just turned there
+        if let Some(last) = self.guard_stops.last() {
+            if self.status != GuardPatrolStatus::StuckInLoop && trail.last() != Some(last) {
+                trail.push(last.clone());
+            }
+        }
+        trail
+    }
+
+    pub fn cycle(&self) -> Option<Cycle> {
+        if self.status != GuardPatrolStatus::StuckInLoop {
+            return None;
+        }
+        let entry = GuardStop {
+            pos: self.guard.position,
+            direction: self.guard.direction,
+        };
+        let trail = self.trail();
+        let start = trail.iter().position(|state| *state == entry)?;
+
+        // The last state of the loop only turns to get back to the entry
+        let moves = trail[start..]
+            .windows(2)
+            .filter(|states| states[0].pos != states[1].pos)
+            .count();
+        Some(Cycle {
+            entry,
+            moves,
+            turns: trail.len() - start - moves,
+        })
+    }
+
+    // The map with the trail drawn like in the puzzle statement, the guard at its start
+    pub fn render_trail(&self) -> Grid<char> {
+        let mut tiles = self.map.tiles();
+        draw_trail(&mut tiles, &self.trail());
+        tiles[self.guard.initial_position] = self.guard.initial_direction.arrow();
+        tiles
+    }
+
+    // Puts an obstruction on the map of this guard only, and starts its patrol over
+    pub fn add_obstruction(&mut self, position: Position) {
+        self.map.add_obstacle(&position);
+        self.reset();
+    }
+
+    fn describe_status(&self) -> String {
+        match self.cycle() {
+            Some(cycle) => format!(
+                "{:?} in a cycle of {} moves and {} turns from {} facing {:?}",
+                self.status, cycle.moves, cycle.turns, cycle.entry.pos, cycle.entry.direction
+            ),
+            None => format!("{:?}", self.status),
+        }
+    }
+
+    fn get_number_of_distinct_positions(&self) -> usize {
+        let distinct_positions = self.guard.trail.iter().collect::<HashSet<_>>();
+        distinct_positions.len()
+    }
+
+    // Only the positions of the initial trail can change the patrol. They are checked in
+    // parallel and returned in the order of the trail
+    fn find_positions_for_infinite_loop(&mut self) -> Vec<Position> {
+        self.run();
+
+        let mut seen = HashSet::new();
+        let candidates: Vec<Position> = self
+            .guard
+            .trail
+            .iter()
+            .filter(|position| seen.insert(**position))
+            .copied()
+            .collect();
+
+        let map = &self.map;
+        let jump_table = &JumpTable::new(map);
+        let start = self.guard.initial_position;
+        let direction = self.guard.initial_direction;
+        let workers = thread::available_parallelism().map_or(1, NonZeroUsize::get);
+        let chunk_size = candidates.len().div_ceil(workers).max(1);
+
+        thread::scope(|scope| {
+            let handles: Vec<_> = candidates
+                .chunks(chunk_size)
+                .map(|chunk| {
+                    scope.spawn(move || {
+                        let mut finder = LoopFinder::new(map, jump_table);
+                        chunk
+                            .iter()
+                            .filter(|obstacle| {
+                                finder.is_stuck_with_obstacle(start, direction, **obstacle)
+                            })
+                            .copied()
+                            .collect::<Vec<_>>()
+                    })
+                })
+                .collect();
+            handles
+                .into_iter()
+                .flat_map(|handle| handle.join().expect("a loop finder panicked"))
+                .collect()
+        })
+    }
+}
+
+// Guards patrolling the same map, each one on its own: they don't block each other
+#[derive(Debug, PartialEq, Clone)]
+pub struct GuardPatrols {
+    patrols: Vec<GuardPatrol>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+struct Coverage {
+    // Distinct positions of every guard, in the order of the guards
+    per_guard: Vec<usize>,
+    // Distinct positions of all the guards together
+    combined: usize,
+    // Positions visited by more than one guard
+    shared: usize,
+}
+
+impl GuardPatrols {
+    // Guards are listed in reading order, and start in the direction of their marker
+    pub fn from_input(input: &str) -> Result<Self, ParseError> {
+        let lines: Vec<_> = input_lines(DAY, input).collect();
+        let markers = parse_grid(&lines, |char| match char {
+            '.' | '#' => Ok(char),
+            _ if Direction::from_arrow(char).is_some() => Ok(char),
+            _ => bail!("expected '.', '#' or a guard ('^', '>', 'v' or '<')"),
+        })?;
+        let guards: Vec<Guard> = markers
+            .iter()
+            .filter_map(|(position, char)| {
+                Direction::from_arrow(*char).map(|direction| Guard::new(position, direction))
+            })
+            .collect();
+
+        if guards.is_empty() {
+            let message = "no guard found, expected one of '^', '>', 'v' or '<'";
+            let error = match input_lines(DAY, input).last() {
+                Some(last_line) => last_line.error_at_end(message),
+                None => ParseError::new(1, 1, "", message).with_day(DAY),
+            };
+            return Err(error);
+        }
+
+        let map = Map {
+            grid: markers.map(|char| match char {
+                '#' => Tile::Obstacle,
+                _ => Tile::Empty,
+            }),
+        };
+        Result::Ok(Self {
+            patrols: guards
+                .into_iter()
+                .map(|guard| GuardPatrol::new(guard, map.clone()))
+                .collect(),
+        })
+    }
+
+    pub fn patrols(&self) -> &[GuardPatrol] {
+        &self.patrols
+    }
+
+    pub fn run(&mut self) {
+        self.patrols.iter_mut().for_each(GuardPatrol::run);
+    }
+
+    fn coverage(&self) -> Coverage {
+        let mut guards_per_position: HashMap<Position, usize> = HashMap::new();
+        let per_guard = self
+            .patrols
+            .iter()
+            .map(|patrol| {
+                let distinct_positions = patrol.guard.trail.iter().collect::<HashSet<_>>();
+                for position in &distinct_positions {
+                    *guards_per_position.entry(**position).or_default() += 1;
+                }
+                distinct_positions.len()
+            })
+            .collect();
+
+        Coverage {
+            per_guard,
+            combined: guards_per_position.len(),
+            shared: guards_per_position
+                .values()
+                .filter(|guards| **guards > 1)
+                .count(),
+        }
+    }
+
+    // Positions trapping at least one guard, guard after guard
+    fn find_positions_for_infinite_loop(&mut self) -> Vec<Position> {
+        let mut seen = HashSet::new();
+        self.patrols
+            .iter_mut()
+            .flat_map(GuardPatrol::find_positions_for_infinite_loop)
+            .filter(|position| seen.insert(*position))
+            .collect()
+    }
+}
+
+// Each tick walks the guard until the next obstacle or out of the map
+impl Simulation for GuardPatrol {
+    fn tick(&mut self) -> bool {
+        if matches!(
+            self.status,
+            GuardPatrolStatus::Finished | GuardPatrolStatus::StuckInLoop
+        ) {
+            return false;
+        }
+        GuardPatrol::tick(self);
+        true
+    }
+
+    fn render(&self) -> String {
+        format!(
+            "{}\n\n{}, {} distinct positions",
+            self.render_trail(),
+            self.describe_status(),
+            self.get_number_of_distinct_positions()
+        )
+    }
+
+    fn tiles(&self) -> Grid<char> {
+        let mut tiles = self.map.tiles();
+        for position in &self.guard.trail {
+            tiles[*position] = 'X';
+        }
+        tiles[self.guard.position] = self.guard.direction.arrow();
+        tiles
+    }
+
+    fn palette(&self) -> Palette {
+        patrol_palette()
+    }
+}
+
+fn patrol_palette() -> Palette {
+    Palette::default()
+        .with('X', [230, 190, 60])
+        .with('O', [60, 160, 220])
+        .with_all("^>v<", [220, 40, 40])
+}
+
+// '|' where the guard walked up or down, '-' left or right, and '+' for both
+fn draw_trail(tiles: &mut Grid<char>, trail: &[GuardStop]) {
+    for state in trail {
+        let mark = match state.direction {
+            Direction::Up | Direction::Down => '|',
+            Direction::Right | Direction::Left => '-',
+        };
+        tiles[state.pos] = match tiles[state.pos] {
+            '.' => mark,
+            tile if tile == mark => mark,
+            _ => '+',
+        };
+    }
+}
+
+// Every guard walks to its next obstacle on each tick
+impl Simulation for GuardPatrols {
+    fn tick(&mut self) -> bool {
+        let mut ongoing = false;
+        for patrol in &mut self.patrols {
+            ongoing |= Simulation::tick(patrol);
+        }
+        ongoing
+    }
+
+    // The trails like in the puzzle statement, with every guard where it stands
+    fn render(&self) -> String {
+        let mut tiles = self.patrols[0].map.tiles();
+        for patrol in &self.patrols {
+            draw_trail(&mut tiles, &patrol.trail());
+        }
+        for patrol in &self.patrols {
+            tiles[patrol.guard.position] = patrol.guard.direction.arrow();
+        }
+
+        let coverage = self.coverage();
+        let mut render = format!("{}\n", tiles);
+        for (index, (patrol, distinct_positions)) in
+            self.patrols.iter().zip(&coverage.per_guard).enumerate()
+        {
+            render.push_str(&format!(
+                "\nGuard {}: {}, {} distinct positions",
+                index + 1,
+                patrol.describe_status(),
+                distinct_positions
+            ));
+        }
+        render.push_str(&format!(
+            "\n{} distinct positions, {} shared by several guards",
+            coverage.combined, coverage.shared
+        ));
+        render
+    }
+
+    // Trails first, so that no guard is hidden by the trail of another one
+    fn tiles(&self) -> Grid<char> {
+        let mut tiles = self.patrols[0].map.tiles();
+        for patrol in &self.patrols {
+            for position in &patrol.guard.trail {
+                tiles[*position] = 'X';
+            }
+        }
+        for patrol in &self.patrols {
+            tiles[patrol.guard.position] = patrol.guard.direction.arrow();
+        }
+        tiles
+    }
+
+    fn palette(&self) -> Palette {
+        patrol_palette()
+    }
+}
+
+// Guard state of a worker looking for loops, the map and its jump table being shared
+struct LoopFinder<'a> {
+    map: &'a Map,
+    jump_table: &'a JumpTable,
+    visited: StateSet,
+}
+
+impl<'a> LoopFinder<'a> {
+    fn new(map: &'a Map, jump_table: &'a JumpTable) -> Self {
+        Self {
+            map,
+            jump_table,
+            visited: StateSet::new(map),
+        }
+    }
+
+    // Patrol from the start with the added obstacle, jumping from stop to stop
+    fn is_stuck_with_obstacle(
+        &mut self,
+        start: Position,
+        mut direction: Direction,
+        obstacle: Position,
+    ) -> bool {
+        self.visited.clear();
+        let mut position = start;
+        self.visited.insert(self.map.state_of(&position, direction));
+
+        while let Some(stop) =
+            self.jump_table
+                .next_stop(self.map, position, direction, Some(obstacle))
+        {
+            position = stop;
+            direction = direction.turn_right();
+            if !self.visited.insert(self.map.state_of(&position, direction)) {
+                return true;
+            }
+        }
+        false
+    }
+}
+
+impl Guard {
+    fn new(position: Position, direction: Direction) -> Self {
+        Self {
+            position,
+            initial_position: position,
+            direction,
+            initial_direction: direction,
+            trail: Vec::new(),
+        }
+    }
+
+    fn reset(&mut self) {
+        self.position = self.initial_position;
+        self.direction = self.initial_direction;
+        self.trail.clear();
+    }
+
+    fn move_on_map(&mut self, map: &Map) -> GuardMoveOutcome {
+        if self.trail.is_empty() {
+            self.trail.push(self.position);
+        }
+
+        loop {
+            let new_position = self.position.step(self.direction);
+
+            if map.is_position_an_obstacle(&new_position) {
+                self.turn();
+                return GuardMoveOutcome::Blocked;
+            }
+
+            if !map.grid.contains(new_position) {
+                return GuardMoveOutcome::Exited;
+            }
+
+            self.position = new_position;
+            self.trail.push(new_position);
+        }
+    }
+
+    fn turn(&mut self) {
+        self.direction = self.direction.turn_right();
+    }
+}
+
+//region Part 1
+
+fn solve_part_1(patrols: &GuardPatrols) -> Result<String> {
+    let mut patrols = patrols.clone();
+    patrols.run();
+    Ok(format!("{}", patrols.coverage().combined))
+}
+//endregion
+
+//region Part 2
+
+fn solve_part_2(patrols: &GuardPatrols) -> Result<String> {
+    let mut patrols = patrols.clone();
+    let positions = patrols.find_positions_for_infinite_loop();
+
+    Ok(format!("{}", positions.len()))
+}
+//endregion
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: &'static str = DAY;
+
+    type Input = GuardPatrols;
+
+    fn parse_input(input_data: &str) -> Result<Self::Input> {
+        Ok(GuardPatrols::from_input(input_data)?)
+    }
+
+    fn solve_part_1(patrol: &Self::Input) -> Result<String> {
+        solve_part_1(patrol)
+    }
+
+    fn solve_part_2(patrol: &Self::Input) -> Result<String> {
+        solve_part_2(patrol)
+    }
+
+    // Part 2 shows the guard stuck in a loop by the first obstruction found
+    fn simulation(patrols: &Self::Input, part: Part) -> Option<Box<dyn Simulation + '_>> {
+        let mut patrols = patrols.clone();
+        if part == Part::Two {
+            let obstruction = patrols
+                .find_positions_for_infinite_loop()
+                .into_iter()
+                .next()?;
+            for patrol in &mut patrols.patrols {
+                patrol.add_obstruction(obstruction);
+            }
+        }
+        Some(Box::new(patrols))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::days::day_06::{
+        Cycle, GuardPatrol, GuardPatrolStatus, GuardPatrols, GuardStop, JumpTable, LoopFinder,
+        StateSet, Tile,
+    };
+    use crate::image::ImageFormat;
+    use crate::position::{Direction, Position};
+    use crate::simulation::{self, ExportOptions, Simulation, Visualizer, DEFAULT_DELAY};
+    use std::collections::HashSet;
+
+    fn get_input_data() -> String {
+        "....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#..."
+            .into()
+    }
+
+    fn single_patrol(input: &str) -> GuardPatrol {
+        GuardPatrols::from_input(input).unwrap().patrols.remove(0)
+    }
+
+    #[test]
+    fn should_create_patrol_from_data() {
+        // Test setup
+        let input_data = get_input_data();
+        let expected_width: usize = 10;
+        let expected_height: usize = 10;
+        let expected_nobstacles: usize = 8;
+        let expected_position = Position { row: 6, col: 4 };
+
+        // Given a patrol created for an input
+        let patrol = single_patrol(&input_data);
+
+        // then the map should be created correctly
+        let map = patrol.map;
+        assert_eq!(
+            map.grid.width(),
+            expected_width,
+            "map width is expected to be {} (actual: {})",
+            expected_width,
+            map.grid.width()
+        );
+        assert_eq!(
+            map.grid.height(),
+            expected_height,
+            "map height is expected to be {} (actual: {})",
+            expected_height,
+            map.grid.height()
+        );
+        let nobstacles = map.grid.find_all(|tile| *tile == Tile::Obstacle).len();
+        assert_eq!(
+            nobstacles, expected_nobstacles,
+            "number or obstacles is expected to be {} (actual: {})",
+            expected_nobstacles, nobstacles
+        );
+
+        // And the quard should be also created correctly
+        let guard = patrol.guard;
+        assert_eq!(
+            guard.position, expected_position,
+            "guard is expected to be at position {:?} (actual: {:?}",
+            expected_position, guard.position
+        );
+        assert_eq!(guard.direction, Direction::Up);
+    }
+
+    #[test]
+    fn should_have_guard_to_first_obstacle_on_first_tick() {
+        // Test setup
+        let input_data = get_input_data();
+        let expected_position = Position { row: 1, col: 4 };
+        let expected_direction = Direction::Right;
+        let expected_trail = [
+            Position { row: 6, col: 4 },
+            Position { row: 5, col: 4 },
+            Position { row: 4, col: 4 },
+            Position { row: 3, col: 4 },
+            Position { row: 2, col: 4 },
+            Position { row: 1, col: 4 },
+        ];
+
+        // Given a new patrol
+        let mut patrol = single_patrol(&input_data);
+
+        // When the first tick is called
+        patrol.tick();
+
+        // Then the guard should be positioned and oriented correctly
+        let guard = patrol.guard;
+        assert_eq!(
+            guard.position, expected_position,
+            "Guard's position is expected to be at position {:?} (actual: {:?}",
+            expected_position, guard.position
+        );
+        assert_eq!(
+            guard.direction, expected_direction,
+            "Guard's direction is expected to be {:?} (actual: {:?})",
+            expected_direction, guard.direction
+        );
+
+        // And the trail should be the one expcted
+        assert_eq!(
+            guard.trail, expected_trail,
+            "Guard's trail differs from expected trail"
+        );
+    }
+
+    #[test]
+    fn should_finish_on_expected_ending_position_on_run() {
+        // Test setup
+        let input_data = get_input_data();
+        let expected_position = Position { row: 9, col: 7 };
+        let expected_stops = [
+            GuardStop {
+                pos: Position { row: 6, col: 4 },
+                direction: Direction::Up,
+            },
+            GuardStop {
+                pos: Position { row: 1, col: 4 },
+                direction: Direction::Right,
+            },
+            GuardStop {
+                pos: Position { row: 1, col: 8 },
+                direction: Direction::Down,
+            },
+            GuardStop {
+                pos: Position { row: 6, col: 8 },
+                direction: Direction::Left,
+            },
+            GuardStop {
+                pos: Position { row: 6, col: 2 },
+                direction: Direction::Up,
+            },
+            GuardStop {
+                pos: Position { row: 4, col: 2 },
+                direction: Direction::Right,
+            },
+            GuardStop {
+                pos: Position { row: 4, col: 6 },
+                direction: Direction::Down,
+            },
+            GuardStop {
+                pos: Position { row: 8, col: 6 },
+                direction: Direction::Left,
+            },
+            GuardStop {
+                pos: Position { row: 8, col: 1 },
+                direction: Direction::Up,
+            },
+            GuardStop {
+                pos: Position { row: 7, col: 1 },
+                direction: Direction::Right,
+            },
+            GuardStop {
+                pos: Position { row: 7, col: 7 },
+                direction: Direction::Down,
+            },
+            GuardStop {
+                pos: Position { row: 9, col: 7 },
+                direction: Direction::Down,
+            },
+        ];
+        let expected_outcome = GuardPatrolStatus::Finished;
+
+        // Given a new patrol
+        let mut patrol = single_patrol(&input_data);
+
+        // When run is called
+        patrol.run();
+
+        // Then it should end as expected
+        assert_eq!(
+            patrol.status, expected_outcome,
+            "Expected outcome: {:?} (actual: {:?}",
+            expected_outcome, patrol.status
+        );
+
+        // And the guard should be at the expected position
+        assert_eq!(
+            patrol.guard.position, expected_position,
+            "Expected guard's position: {:?} (actual: {:?})",
+            expected_position, patrol.guard.position
+        );
+
+        // And the guard should have stopped to all the expected stops
+        for (i, expected_stop) in expected_stops.iter().enumerate() {
+            let guard_stop = patrol.guard_stops.get(i).unwrap();
+            assert_eq!(
+                guard_stop, expected_stop,
+                "Guard's stop #{} differs from expected one (expected: {:?}, actual: {:?})",
+                i, expected_stop, guard_stop
+            )
+        }
+    }
+
+    #[test]
+    fn should_compute_number_of_distinct_positions() {
+        // Test setup
+        let input_data = get_input_data();
+        let expected_n_distinct_positions = 41;
+
+        // Given a new patrol
+        let mut patrol = single_patrol(&input_data);
+
+        // When run is called and the patrol is finished
+        patrol.run();
+        assert_eq!(patrol.status, GuardPatrolStatus::Finished);
+
+        // Then the number of distinct position should be the have expected
+        let n_positions = patrol.get_number_of_distinct_positions();
+        assert_eq!(
+            n_positions, expected_n_distinct_positions,
+            "Expected number of distinct positions: {} (actual: {})",
+            n_positions, expected_n_distinct_positions
+        );
+    }
+
+    #[test]
+    fn should_detect_infinite_loop() {
+        // Test setup
+        let input_data = get_input_data();
+        let obstacle_position = Position { row: 6, col: 3 };
+        let expected_outcome = GuardPatrolStatus::StuckInLoop;
+
+        // Given a new patrol that will triggered an infinite loop
+        let mut patrol = single_patrol(&input_data);
+        patrol.map.add_obstacle(&obstacle_position);
+
+        // When we run the patrol
+        patrol.run();
+
+        // Then the outcome should reflects the infinite loop
+        assert_eq!(
+            patrol.status, expected_outcome,
+            "Expected outcome: {:?} (actual: {:?})",
+            expected_outcome, patrol.status
+        );
+    }
+
+    #[test]
+    fn should_find_positions_for_infinite_loop() {
+        // Test setup
+        let input_data = get_input_data();
+        let expected_number = 6;
+
+        // Given a new patrol that will triggered an infinite loop
+        let mut patrol = single_patrol(&input_data);
+
+        // When we find the positions that triggers an infinite loop
+        let positions = patrol.find_positions_for_infinite_loop();
+
+        assert_eq!(
+            positions.len(),
+            expected_number,
+            "Expected number of positions: {} (actual: {})",
+            expected_number,
+            positions.len()
+        );
+    }
+
+    #[test]
+    fn should_jump_to_next_obstacle() {
+        // Given the jump table of the example map
+        let patrol = single_patrol(&get_input_data());
+        let jump_table = JumpTable::new(&patrol.map);
+        let start = Position { row: 6, col: 4 };
+
+        // Then the guard should stop right before the obstacles
+        assert_eq!(
+            Some(Position { row: 1, col: 4 }),
+            jump_table.next_stop(&patrol.map, start, Direction::Up, None)
+        );
+        assert_eq!(
+            Some(Position { row: 6, col: 2 }),
+            jump_table.next_stop(&patrol.map, start, Direction::Left, None)
+        );
+        // And should exit the map when there is no obstacle
+        assert_eq!(
+            None,
+            jump_table.next_stop(&patrol.map, start, Direction::Right, None)
+        );
+
+        // And an added obstacle should only stop the guard when it is ahead and closer
+        let added = |row, col| Some(Position { row, col });
+        assert_eq!(
+            Some(Position { row: 4, col: 4 }),
+            jump_table.next_stop(&patrol.map, start, Direction::Up, added(3, 4))
+        );
+        assert_eq!(
+            Some(Position { row: 6, col: 7 }),
+            jump_table.next_stop(&patrol.map, start, Direction::Right, added(6, 8))
+        );
+        assert_eq!(
+            Some(Position { row: 1, col: 4 }),
+            jump_table.next_stop(&patrol.map, start, Direction::Up, added(7, 4))
+        );
+        assert_eq!(
+            Some(Position { row: 6, col: 2 }),
+            jump_table.next_stop(&patrol.map, start, Direction::Left, added(6, 0))
+        );
+        assert_eq!(
+            None,
+            jump_table.next_stop(&patrol.map, start, Direction::Right, added(5, 8))
+        );
+    }
+
+    #[test]
+    fn should_return_loop_positions_in_trail_order() {
+        // Given a patrol whose trail is known
+        let mut patrol = single_patrol(&get_input_data());
+        patrol.run();
+        let mut seen = HashSet::new();
+        let candidates: Vec<Position> = patrol
+            .guard
+            .trail
+            .iter()
+            .filter(|position| seen.insert(**position))
+            .copied()
+            .collect();
+
+        // When the candidates are checked one by one by a single worker
+        let jump_table = JumpTable::new(&patrol.map);
+        let mut finder = LoopFinder::new(&patrol.map, &jump_table);
+        let start = patrol.guard.initial_position;
+        let expected: Vec<Position> = candidates
+            .into_iter()
+            .filter(|obstacle| finder.is_stuck_with_obstacle(start, Direction::Up, *obstacle))
+            .collect();
+
+        // Then the parallel search should find the same positions in the same order
+        let mut patrol = single_patrol(&get_input_data());
+        assert_eq!(expected, patrol.find_positions_for_infinite_loop());
+        assert_eq!(expected, patrol.find_positions_for_infinite_loop());
+        assert_eq!(Position { row: 6, col: 3 }, expected[0]);
+    }
+
+    #[test]
+    fn should_track_visited_states() {
+        // Given an empty set of states
+        let patrol = single_patrol(&get_input_data());
+        let mut visited = StateSet::new(&patrol.map);
+
+        // Then a state should only be new once
+        let state = patrol
+            .map
+            .state_of(&Position { row: 9, col: 9 }, Direction::Left);
+        assert!(visited.insert(state));
+        assert!(!visited.insert(state));
+        assert!(visited.insert(state - 1));
+
+        // And clearing should forget every state
+        visited.clear();
+        assert!(visited.insert(state));
+    }
+
+    #[test]
+    fn should_start_in_the_direction_of_the_marker() {
+        // Given a guard starting towards the right
+        let input_data = get_input_data().replace('^', ">");
+        let mut patrol = single_patrol(&input_data);
+        assert_eq!(patrol.guard.direction, Direction::Right);
+
+        // When the patrol is run
+        patrol.run();
+
+        // Then the guard should leave the map straight away
+        assert_eq!(patrol.status, GuardPatrolStatus::Finished);
+        assert_eq!(patrol.get_number_of_distinct_positions(), 6);
+
+        // And a reset should turn it back to its initial direction
+        patrol.reset();
+        assert_eq!(patrol.guard.direction, Direction::Right);
+    }
+
+    #[test]
+    fn should_fail_without_guard() {
+        // Given a map without any guard
+        let input_data = get_input_data().replace('^', ".");
+
+        // When the patrols are created
+        let error = GuardPatrols::from_input(&input_data).unwrap_err();
+
+        // Then the missing guard should be reported
+        assert!(error
+            .to_string()
+            .contains("no guard found, expected one of '^', '>', 'v' or '<'"));
+        assert!(GuardPatrols::from_input("").is_err());
+        assert!(GuardPatrols::from_input("..\n.x").is_err());
+    }
+
+    #[test]
+    fn should_combine_coverage_of_several_guards() {
+        // Given a second guard going left from the right edge
+        let input_data = get_input_data().replace(".......#..", ".......#.<");
+        let mut patrols = GuardPatrols::from_input(&input_data).unwrap();
+        assert_eq!(patrols.patrols.len(), 2);
+
+        // When both guards patrol
+        patrols.run();
+
+        // Then every guard should have its own trail, in reading order
+        let coverage = patrols.coverage();
+        assert_eq!(coverage.per_guard, vec![6, 41]);
+        assert_eq!(
+            patrols.patrols[0].guard.trail,
+            vec![
+                Position { row: 4, col: 9 },
+                Position { row: 4, col: 8 },
+                Position { row: 3, col: 8 },
+                Position { row: 2, col: 8 },
+                Position { row: 1, col: 8 },
+                Position { row: 0, col: 8 },
+            ]
+        );
+
+        // And the positions visited by both guards should only be counted once
+        assert_eq!(coverage.combined, 43);
+        assert_eq!(coverage.shared, 4);
+    }
+
+    #[test]
+    fn should_list_trail_with_directions() {
+        // Given a patrol that ran to the end
+        let mut patrol = single_patrol(&get_input_data());
+        patrol.run();
+
+        // When the trail is listed
+        let trail = patrol.trail();
+
+        // Then it should start with the guard walking up to the first obstacle and turning
+        let state = |row, col, direction| GuardStop {
+            pos: Position { row, col },
+            direction,
+        };
+        assert_eq!(
+            trail[..7],
+            [
+                state(6, 4, Direction::Up),
+                state(5, 4, Direction::Up),
+                state(4, 4, Direction::Up),
+                state(3, 4, Direction::Up),
+                state(2, 4, Direction::Up),
+                state(1, 4, Direction::Up),
+                state(1, 4, Direction::Right),
+            ]
+        );
+
+        // And end on the way out of the map, every state being listed once
+        assert_eq!(trail.len(), 55);
+        assert_eq!(trail.last(), Some(&state(9, 7, Direction::Down)));
+        assert_ne!(trail[trail.len() - 2], trail[trail.len() - 1]);
+
+        // While a guard that just turned should end facing its new direction
+        patrol.reset();
+        patrol.tick();
+        assert_eq!(patrol.trail()[..], trail[..7]);
+        let distinct_positions = trail.iter().map(|state| state.pos).collect::<HashSet<_>>();
+        assert_eq!(distinct_positions.len(), 41);
+        assert_eq!(patrol.cycle(), None);
+    }
+
+    #[test]
+    fn should_describe_loop_like_the_puzzle_statement() {
+        // Given the first obstruction of the puzzle statement
+        let mut patrol = single_patrol(&get_input_data());
+        patrol.add_obstruction(Position { row: 6, col: 3 });
+
+        // When the guard gets stuck
+        patrol.run();
+        assert_eq!(patrol.status, GuardPatrolStatus::StuckInLoop);
+
+        // Then the cycle should start from the first stop
+        assert_eq!(
+            patrol.cycle(),
+            Some(Cycle {
+                entry: GuardStop {
+                    pos: Position { row: 6, col: 4 },
+                    direction: Direction::Up,
+                },
+                moves: 18,
+                turns: 4,
+            })
+        );
+
+        // And the trail should be drawn like in the puzzle statement
+        assert_eq!(
+            patrol.render_trail().to_string(),
+            "....#.....
+....+---+#
+....|...|.
+..#.|...|.
+....|..#|.
+....|...|.
+.#.O^---+.
+........#.
+#.........
+......#..."
+        );
+    }
+
+    #[test]
+    fn should_render_the_frame_where_the_guard_exits() {
+        // Given the patrol of the example, which exits on its 11th move
+        let patrols = GuardPatrols::from_input(&get_input_data()).unwrap();
+
+        // When playing it to the end
+        let mut visualizer = Visualizer::new("Day 06", DEFAULT_DELAY, || Box::new(patrols.clone()));
+        visualizer.jump_to(usize::MAX);
+
+        // Then the exit should be the last tick
+        assert!(visualizer.is_finished());
+        assert_eq!(11, visualizer.tick());
+
+        // And its frame should show the guard leaving the map
+        let mut exited = patrols.clone();
+        for _ in 0..11 {
+            assert!(Simulation::tick(&mut exited));
+        }
+        assert_eq!(Some(&'v'), exited.tiles().get(Position::new(9, 7)));
+        assert!(exited.render().contains("Guard 1: Finished"));
+        assert!(!Simulation::tick(&mut exited));
+
+        // And it should be exported, but not a tick after it
+        let mut options = ExportOptions::new(ImageFormat::Ppm);
+        options.tick = 11;
+        assert!(simulation::export(&mut patrols.clone(), &options).is_ok());
+        options.tick = 12;
+        assert!(simulation::export(&mut patrols.clone(), &options).is_err());
+    }
+}
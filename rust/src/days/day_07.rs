@@ -0,0 +1,308 @@
+use crate::parse::{input_lines, ParseError};
+use crate::Solution;
+use anyhow::*;
+
+const DAY: &str = "07";
+
+#[derive(Debug)]
+pub struct CalibrationEntry {
+    target: usize,
+    numbers: Vec<usize>,
+}
+
+fn process_input(input: &str) -> Result<Vec<CalibrationEntry>, ParseError> {
+    let mut entries: Vec<CalibrationEntry> = Vec::new();
+
+    for line in input_lines(DAY, input) {
+        let (target, numbers) = line.split_once(line.text, ":")?;
+        let target: usize = line.parse(target)?;
+        let numbers: Vec<usize> = line.integers(numbers)?;
+
+        entries.push(CalibrationEntry { target, numbers })
+    }
+
+    Result::Ok(entries)
+}
+
+#[derive(Debug, Copy, Clone)]
+enum Operator {
+    Plus,
+    Mult,
+    Concat,
+}
+
+// The power of ten just above the number, to append it to another one
+fn concat_base(number: usize) -> usize {
+    let mut base = 10;
+    while base <= number {
+        base *= 10;
+    }
+    base
+}
+
+// Counts the ways to reach the target by undoing the operators from the last number: a sum
+// can't exceed the target, a product must divide it and a concatenation must end with the
+// number, which prunes most branches.
+// Counting stops at the limit, usize::MAX counts them all
+fn compute_number_of_calibration(
+    target: usize,
+    numbers: &[usize],
+    accepted_operators: &[Operator],
+    limit: usize,
+) -> usize {
+    let Some((&last, rest)) = numbers.split_last() else {
+        return 0;
+    };
+    if rest.is_empty() {
+        return usize::from(target == last);
+    }
+
+    let mut count = 0;
+    for operator in accepted_operators {
+        let previous = match operator {
+            Operator::Plus => target.checked_sub(last),
+            // Any result of the other numbers works when multiplied by zero
+            Operator::Mult if last == 0 => {
+                if target == 0 {
+                    let combinations = accepted_operators
+                        .len()
+                        .saturating_pow(rest.len() as u32 - 1);
+                    count += combinations.min(limit - count);
+                }
+                None
+            }
+            Operator::Mult => target.is_multiple_of(last).then_some(target / last),
+            Operator::Concat => {
+                let base = concat_base(last);
+                (target % base == last).then_some(target / base)
+            }
+        };
+        if let Some(previous) = previous {
+            count +=
+                compute_number_of_calibration(previous, rest, accepted_operators, limit - count);
+        }
+        if count >= limit {
+            break;
+        }
+    }
+
+    count
+}
+
+fn is_calibrated(target: usize, numbers: &[usize], accepted_operators: &[Operator]) -> bool {
+    compute_number_of_calibration(target, numbers, accepted_operators, 1) > 0
+}
+
+fn compute_calibration_result_total(
+    entries: &[CalibrationEntry],
+    accepted_operators: &[Operator],
+) -> usize {
+    let mut total = 0;
+
+    for entry in entries {
+        if is_calibrated(entry.target, &entry.numbers, accepted_operators) {
+            total += entry.target;
+        }
+    }
+
+    total
+}
+
+//region Part 1
+
+fn solve_part_1(entries: &[CalibrationEntry]) -> Result<String> {
+    Ok(format!(
+        "{}",
+        compute_calibration_result_total(entries, &[Operator::Plus, Operator::Mult])
+    ))
+}
+//endregion
+
+//region Part 2
+
+fn solve_part_2(entries: &[CalibrationEntry]) -> Result<String> {
+    Ok(format!(
+        "{}",
+        compute_calibration_result_total(
+            entries,
+            &[Operator::Plus, Operator::Mult, Operator::Concat]
+        )
+    ))
+}
+
+//endregion
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: &'static str = DAY;
+
+    type Input = Vec<CalibrationEntry>;
+
+    fn parse_input(input_data: &str) -> Result<Self::Input> {
+        Ok(process_input(input_data)?)
+    }
+
+    fn solve_part_1(entries: &Self::Input) -> Result<String> {
+        solve_part_1(entries)
+    }
+
+    fn solve_part_2(entries: &Self::Input) -> Result<String> {
+        solve_part_2(entries)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_reject_malformed_numbers() {
+        // Given numbers with stray chars
+        for input in ["190: 10 1a", "190: 10 1.9", "190: 10 x 19"] {
+            // When parsing them
+            let error = process_input(input).unwrap_err();
+
+            // Then the offending value should be reported
+            assert_eq!((1, 9), (error.line, error.column), "{}", input);
+        }
+    }
+
+    #[test]
+    fn should_work_with_one_operator() {
+        // Test setup
+        let expected_result = 1;
+
+        // Given a target and some numbers
+        let target = 190;
+        let input = vec![10, 19];
+
+        // When computing the number of possibilities
+        let result = compute_number_of_calibration(
+            target,
+            &input,
+            &[Operator::Plus, Operator::Mult],
+            usize::MAX,
+        );
+
+        // Then the result should be the expected one
+        assert_eq!(
+            result, expected_result,
+            "Expected number of possibilities: {} (actual: {})",
+            expected_result, result
+        );
+    }
+
+    #[test]
+    fn should_work_with_two_operators() {
+        // Test setup
+        let expected_result = 2;
+
+        // Given a target and some numbers
+        let target = 3267;
+        let input = vec![81, 40, 27];
+
+        // When computing the number of possibilities
+        let result = compute_number_of_calibration(
+            target,
+            &input,
+            &[Operator::Plus, Operator::Mult],
+            usize::MAX,
+        );
+
+        // Then the result should be the expected one
+        assert_eq!(
+            result, expected_result,
+            "Expected number of possibilities: {} (actual: {})",
+            expected_result, result
+        );
+    }
+
+    #[test]
+    fn should_count_every_way_to_reach_the_target() {
+        // Given every operator
+        let operators = [Operator::Plus, Operator::Mult, Operator::Concat];
+        let count = |target, numbers: &[usize]| {
+            compute_number_of_calibration(target, numbers, &operators, usize::MAX)
+        };
+
+        // Then every combination reaching the target should be counted
+        assert_eq!(count(2, &[1, 1, 1]), 2);
+        assert_eq!(count(11, &[1, 1, 1]), 2);
+        assert_eq!(count(111, &[1, 1, 1]), 1);
+        assert_eq!(count(156, &[15, 6]), 1);
+        assert_eq!(count(7290, &[6, 8, 6, 15]), 1);
+        assert_eq!(count(1006, &[10, 0, 6]), 1);
+
+        // Even when a product by zero hides the numbers before it
+        let operators = [Operator::Plus, Operator::Mult];
+        assert_eq!(
+            compute_number_of_calibration(0, &[5, 0, 0], &operators, usize::MAX),
+            3
+        );
+        assert!(!is_calibrated(1, &[5, 0, 0], &operators));
+    }
+
+    #[test]
+    fn should_get_the_total_calibration_result_for_part1() {
+        // Test setup
+        let expected_result = 3749;
+
+        // Given some entries
+        let entries = process_input(
+            "190: 10 19
+3267: 81 40 27
+83: 17 5
+156: 15 6
+7290: 6 8 6 15
+161011: 16 10 13
+192: 17 8 14
+21037: 9 7 18 13
+292: 11 6 16 20",
+        )
+        .unwrap();
+
+        // When getting the calibration result
+        let result = compute_calibration_result_total(&entries, &[Operator::Plus, Operator::Mult]);
+
+        // Then it should be the one expected
+        assert_eq!(
+            result, expected_result,
+            "Expected total calibration result: {} (actual:{})",
+            expected_result, result
+        );
+    }
+
+    #[test]
+    fn should_get_the_total_calibration_result_for_part2() {
+        // Test setup
+        let expected_result = 11387;
+
+        // Given some entries
+        let entries = process_input(
+            "190: 10 19
+3267: 81 40 27
+83: 17 5
+156: 15 6
+7290: 6 8 6 15
+161011: 16 10 13
+192: 17 8 14
+21037: 9 7 18 13
+292: 11 6 16 20",
+        )
+        .unwrap();
+
+        // When getting the calibration result
+        let result = compute_calibration_result_total(
+            &entries,
+            &[Operator::Plus, Operator::Mult, Operator::Concat],
+        );
+
+        // Then it should be the one expected
+        assert_eq!(
+            result, expected_result,
+            "Expected total calibration result: {} (actual:{})",
+            expected_result, result
+        );
+    }
+}
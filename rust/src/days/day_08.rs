@@ -1,31 +1,30 @@
+use crate::grid::Grid;
+use crate::parse::{input_lines, parse_grid, InputLine, ParseError};
+use crate::position::Position;
+use crate::Solution;
 use anyhow::*;
-use aoc2024::*;
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashSet};
 use std::result::Result::Ok;
 
 const DAY: &str = "08";
-const SOLUTION_PART_1: &str = "341";
-const SOLUTION_PART_2: &str = "1134";
 
-#[derive(PartialEq, Debug, Copy, Clone, Eq, Hash)]
-struct Position {
-    row: isize,
-    col: isize,
+// The map of the city, and its antennas grouped by frequency
+#[derive(PartialEq, Debug, Clone)]
+pub struct City {
+    map: Grid<char>,
+    networks: Vec<AntennaNetwork>,
 }
 
+// The antennas of one frequency
 #[derive(PartialEq, Debug, Clone)]
-struct AntennaNetwork {
-    width: usize,
-    height: usize,
+pub struct AntennaNetwork {
     frequency: char,
     antennas: Vec<Position>,
 }
 
 impl AntennaNetwork {
-    fn new(frequency: char, height: usize, width: usize) -> AntennaNetwork {
+    fn new(frequency: char) -> AntennaNetwork {
         Self {
-            width,
-            height,
             frequency,
             antennas: vec![],
         }
@@ -37,38 +36,17 @@ impl AntennaNetwork {
 
     fn compute_antinode_for_antenna_couple(
         &self,
+        map: &Grid<char>,
         reference: &Position,
         other: &Position,
     ) -> Option<Position> {
-        let delta_row = reference.row - other.row;
-        let row = reference.row + delta_row;
-
-        if row < 0 || row >= self.height as isize {
-            return None;
-        }
-
-        let delta_col = reference.col - other.col;
-        let col = reference.col + delta_col;
-
-        if col < 0 || col >= self.width as isize {
-            return None;
-        }
-
-        Some(Position { row: row, col: col })
-    }
-
-    fn is_inside_network(&self, position: &Position) -> bool {
-        if position.row < 0 || position.row >= self.height as isize {
-            return false;
-        }
-        if position.col < 0 || position.col >= self.width as isize {
-            return false;
-        }
-        true
+        let antinode = *reference + (*reference - *other);
+        map.contains(antinode).then_some(antinode)
     }
 
     fn compute_antinode_harmonics_for_antenna_couple(
         &self,
+        map: &Grid<char>,
         reference: &Position,
         other: &Position,
     ) -> Vec<Position> {
@@ -86,7 +64,7 @@ impl AntennaNetwork {
                 row: reference.row + delta_row * resonance,
                 col: reference.col + delta_col * resonance,
             };
-            if self.is_inside_network(&first_antinode) {
+            if map.contains(first_antinode) {
                 added += 1;
                 harmonics.push(first_antinode);
             }
@@ -94,7 +72,7 @@ impl AntennaNetwork {
                 row: other.row - delta_row * resonance,
                 col: other.col - delta_col * resonance,
             };
-            if self.is_inside_network(&second_antinode) {
+            if map.contains(second_antinode) {
                 added += 1;
                 harmonics.push(second_antinode);
             }
@@ -108,7 +86,7 @@ impl AntennaNetwork {
         harmonics
     }
 
-    fn compute_antinodes(&self) -> Vec<Position> {
+    fn compute_antinodes(&self, map: &Grid<char>) -> Vec<Position> {
         let mut antinodes: Vec<Position> = vec![];
 
         for i in 0..self.antennas.len() {
@@ -117,13 +95,13 @@ impl AntennaNetwork {
                 let antenna_2 = &self.antennas[j];
 
                 if let Some(position) =
-                    self.compute_antinode_for_antenna_couple(antenna_1, antenna_2)
+                    self.compute_antinode_for_antenna_couple(map, antenna_1, antenna_2)
                 {
                     antinodes.push(position);
                 }
 
                 if let Some(position) =
-                    self.compute_antinode_for_antenna_couple(antenna_2, antenna_1)
+                    self.compute_antinode_for_antenna_couple(map, antenna_2, antenna_1)
                 {
                     antinodes.push(position);
                 }
@@ -132,7 +110,7 @@ impl AntennaNetwork {
         antinodes
     }
 
-    fn compute_antinodes_with_harmonics(&self) -> Vec<Position> {
+    fn compute_antinodes_with_harmonics(&self, map: &Grid<char>) -> Vec<Position> {
         let mut antinodes: Vec<Position> = vec![];
 
         for i in 0..self.antennas.len() {
@@ -141,7 +119,7 @@ impl AntennaNetwork {
                 let antenna_2 = &self.antennas[j];
 
                 for position in
-                    self.compute_antinode_harmonics_for_antenna_couple(antenna_1, antenna_2)
+                    self.compute_antinode_harmonics_for_antenna_couple(map, antenna_1, antenna_2)
                 {
                     if !antinodes.contains(&position) {
                         antinodes.push(position);
@@ -154,54 +132,48 @@ impl AntennaNetwork {
     }
 }
 
-fn process_input(input_data: &str) -> Vec<AntennaNetwork> {
-    let mut grid: HashMap<char, AntennaNetwork> = HashMap::new();
-
-    let lines: Vec<&str> = input_data.lines().collect();
-    let height = lines.len();
-    for (row, line) in lines.iter().enumerate() {
-        let width = line.len();
-
-        for (col, char) in line.chars().enumerate() {
-            if char == '.' {
-                continue;
-            }
-
-            let position = Position {
-                row: row as isize,
-                col: col as isize,
-            };
-
-            match grid.get_mut(&char) {
-                None => {
-                    let mut network = AntennaNetwork::new(char, height, width);
-                    network.add_antenna(position);
-                    grid.insert(char, network);
-                }
-                Some(network) => {
-                    network.add_antenna(position);
-                }
-            };
-        }
+fn process_input(input_data: &str) -> Result<City, ParseError> {
+    let lines: Vec<InputLine> = input_lines(DAY, input_data).collect();
+    let map = parse_grid(&lines, |char| match char {
+        '.' => Ok(char),
+        _ if char.is_ascii_alphanumeric() => Ok(char),
+        _ => bail!("expected '.' or an antenna frequency"),
+    })?;
+
+    let mut networks: BTreeMap<char, AntennaNetwork> = BTreeMap::new();
+    for (position, frequency) in map.iter().filter(|(_, char)| **char != '.') {
+        networks
+            .entry(*frequency)
+            .or_insert_with(|| AntennaNetwork::new(*frequency))
+            .add_antenna(position);
     }
-    Vec::from_iter(grid.values().cloned())
+    Ok(City {
+        networks: networks.into_values().collect(),
+        map,
+    })
 }
 
-fn compute_number_of_antinodes_for_networks(networks: &[AntennaNetwork]) -> usize {
+fn compute_number_of_antinodes_for_networks(
+    map: &Grid<char>,
+    networks: &[AntennaNetwork],
+) -> usize {
     let mut antinodes: HashSet<Position> = HashSet::new();
 
     for network in networks {
-        for position in &network.compute_antinodes() {
+        for position in &network.compute_antinodes(map) {
             antinodes.insert(*position);
         }
     }
     antinodes.len()
 }
 
-fn compute_number_of_harmonical_antinodes_for_networks(networks: &[AntennaNetwork]) -> usize {
+fn compute_number_of_harmonical_antinodes_for_networks(
+    map: &Grid<char>,
+    networks: &[AntennaNetwork],
+) -> usize {
     let mut antinodes: HashSet<Position> = HashSet::new();
     for network in networks {
-        for position in &network.compute_antinodes_with_harmonics() {
+        for position in &network.compute_antinodes_with_harmonics(map) {
             antinodes.insert(*position);
         }
     }
@@ -210,47 +182,42 @@ fn compute_number_of_harmonical_antinodes_for_networks(networks: &[AntennaNetwor
 
 //region Part 1
 
-fn solve_part_1(networks: &[AntennaNetwork]) -> Result<String> {
+fn solve_part_1(city: &City) -> Result<String> {
     Ok(format!(
         "{}",
-        compute_number_of_antinodes_for_networks(networks)
+        compute_number_of_antinodes_for_networks(&city.map, &city.networks)
     ))
 }
 //endregion
 
 //region Part 2
 
-fn solve_part_2(networks: &[AntennaNetwork]) -> Result<String> {
+fn solve_part_2(city: &City) -> Result<String> {
     Ok(format!(
         "{}",
-        compute_number_of_harmonical_antinodes_for_networks(networks)
+        compute_number_of_harmonical_antinodes_for_networks(&city.map, &city.networks)
     ))
 }
 //endregion
 
-fn main() -> Result<()> {
-    start_day(DAY);
-
-    let (networks, duration) = time_function!(process_input(&*get_input_data(DAY)?));
-    println!("Input data loaded in {}", pretty_duration(duration));
-
-    let (answer_part_1, part1_duration) = time_function!(solve_part_1(&networks)?);
-    println!(
-        "Part 1: {} (solved in {})",
-        answer_part_1,
-        pretty_duration(part1_duration)
-    );
-    check_result(&answer_part_1, SOLUTION_PART_1);
-
-    let (answer_part_2, part2_duration) = time_function!(solve_part_2(&networks)?);
-    println!(
-        "Part 2: {} (solved in {})",
-        answer_part_2,
-        pretty_duration(part2_duration)
-    );
-    check_result(&answer_part_2, SOLUTION_PART_2);
-
-    Ok(())
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: &'static str = DAY;
+
+    type Input = City;
+
+    fn parse_input(input_data: &str) -> Result<Self::Input> {
+        Ok(process_input(input_data)?)
+    }
+
+    fn solve_part_1(city: &Self::Input) -> Result<String> {
+        solve_part_1(city)
+    }
+
+    fn solve_part_2(city: &Self::Input) -> Result<String> {
+        solve_part_2(city)
+    }
 }
 
 #[cfg(test)]
@@ -259,12 +226,25 @@ mod tests {
 
     #[test]
     fn should_process_input() {
+        // Given an input
+        let input_data = "............
+........0...
+.....0......
+.......0....
+....0.......
+......A.....
+............
+............
+........A...
+.........A..
+............
+............";
+        let map = Grid::parse(input_data, Ok).unwrap();
+
         // Test setup
         let expected_networks = vec![
             AntennaNetwork {
                 frequency: '0',
-                height: 12,
-                width: 12,
                 antennas: vec![
                     Position { row: 1, col: 8 },
                     Position { row: 2, col: 5 },
@@ -274,8 +254,6 @@ mod tests {
             },
             AntennaNetwork {
                 frequency: 'A',
-                height: 12,
-                width: 12,
                 antennas: vec![
                     Position { row: 5, col: 6 },
                     Position { row: 8, col: 8 },
@@ -284,26 +262,13 @@ mod tests {
             },
         ];
 
-        // Given an input
-        let input_data = "............
-........0...
-.....0......
-.......0....
-....0.......
-......A.....
-............
-............
-........A...
-.........A..
-............
-............";
-
         // When processing it
-        let networks = process_input(input_data);
+        let city = process_input(input_data).unwrap();
 
-        // Then we should obtain the expected networks
+        // Then we should obtain the map and the expected networks
+        assert_eq!(city.map, map);
         for expected_network in expected_networks {
-            for network in networks.iter() {
+            for network in city.networks.iter() {
                 if expected_network.frequency == network.frequency {
                     assert_eq!(expected_network, *network);
                 }
@@ -317,12 +282,12 @@ mod tests {
         let expected_positions = vec![Position { row: 1, col: 3 }, Position { row: 7, col: 6 }];
 
         // Given an antenna network
-        let mut network = AntennaNetwork::new('a', 10, 10);
+        let mut network = AntennaNetwork::new('a');
         network.add_antenna(Position { row: 3, col: 4 });
         network.add_antenna(Position { row: 5, col: 5 });
 
         // When computing the antinode positions
-        let positions = network.compute_antinodes();
+        let positions = network.compute_antinodes(&Grid::new(10, 10, '.'));
 
         // Then they should correspond to the expected ones
         assert_eq!(
@@ -343,13 +308,13 @@ mod tests {
         ];
 
         // Given an antenna network
-        let mut network = AntennaNetwork::new('a', 10, 10);
+        let mut network = AntennaNetwork::new('a');
         network.add_antenna(Position { row: 3, col: 4 });
         network.add_antenna(Position { row: 5, col: 5 });
         network.add_antenna(Position { row: 4, col: 8 });
 
         // When computing the antinode positions
-        let positions = network.compute_antinodes();
+        let positions = network.compute_antinodes(&Grid::new(10, 10, '.'));
 
         // Then they should correspond to the expected ones
         assert_eq!(
@@ -368,8 +333,6 @@ mod tests {
         let networks = vec![
             AntennaNetwork {
                 frequency: '0',
-                height: 12,
-                width: 12,
                 antennas: vec![
                     Position { row: 1, col: 8 },
                     Position { row: 2, col: 5 },
@@ -379,8 +342,6 @@ mod tests {
             },
             AntennaNetwork {
                 frequency: 'A',
-                height: 12,
-                width: 12,
                 antennas: vec![
                     Position { row: 5, col: 6 },
                     Position { row: 8, col: 8 },
@@ -390,7 +351,7 @@ mod tests {
         ];
 
         // When computing the total number of antinodes
-        let result = compute_number_of_antinodes_for_networks(&networks);
+        let result = compute_number_of_antinodes_for_networks(&Grid::new(12, 12, '.'), &networks);
 
         // Then we should get the expected result
         assert_eq!(
@@ -417,8 +378,6 @@ mod tests {
 
         // Given a network
         let network = AntennaNetwork {
-            width: 10,
-            height: 10,
             frequency: 'T',
             antennas: Vec::from([
                 Position { row: 0, col: 0 },
@@ -428,7 +387,7 @@ mod tests {
         };
 
         // When computing antinodes with harmonics
-        let positions = network.compute_antinodes_with_harmonics();
+        let positions = network.compute_antinodes_with_harmonics(&Grid::new(10, 10, '.'));
 
         // Then the positions of the antinodes should be the one expected
         assert_eq!(expected_positions.len(), positions.len());
@@ -451,8 +410,6 @@ mod tests {
         let networks = vec![
             AntennaNetwork {
                 frequency: '0',
-                height: 12,
-                width: 12,
                 antennas: vec![
                     Position { row: 1, col: 8 },
                     Position { row: 2, col: 5 },
@@ -462,8 +419,6 @@ mod tests {
             },
             AntennaNetwork {
                 frequency: 'A',
-                height: 12,
-                width: 12,
                 antennas: vec![
                     Position { row: 5, col: 6 },
                     Position { row: 8, col: 8 },
@@ -473,7 +428,8 @@ mod tests {
         ];
 
         // When computing the total number of antinodes
-        let result = compute_number_of_harmonical_antinodes_for_networks(&networks);
+        let result =
+            compute_number_of_harmonical_antinodes_for_networks(&Grid::new(12, 12, '.'), &networks);
 
         // Then we should get the expected result
         assert_eq!(
@@ -1,16 +1,15 @@
-use crate::diskutil::{
+use crate::days::day_09::diskutil::{
     compact, compact_blocks, compute_checksum, compute_checksum_block, load_disk, load_disk_blocks,
 };
+use crate::parse::{input_lines, ParseError};
+use crate::Solution;
 use anyhow::*;
-use aoc2024::*;
 
 const DAY: &str = "09";
-const SOLUTION_PART_1: &str = "6384282079460";
-const SOLUTION_PART_2: &str = "6408966547049";
 
 mod diskutil {
-    use crate::diskutil::AtomicBlock::{FileBlock, FreeBlock};
-    use crate::diskutil::DiskBlock::{File, FreeSpace};
+    use crate::days::day_09::diskutil::AtomicBlock::{FileBlock, FreeBlock};
+    use crate::days::day_09::diskutil::DiskBlock::{File, FreeSpace};
 
     #[derive(Debug, PartialEq, Copy, Clone)]
     pub enum AtomicBlock {
@@ -184,6 +183,29 @@ mod diskutil {
     }
 }
 
+// The disk map is a single line of digits
+fn check_diskmap(input_data: &str) -> Result<String, ParseError> {
+    let mut lines = input_lines(DAY, input_data).filter(|line| !line.is_blank());
+    let Some(line) = lines.next() else {
+        return Err(ParseError::new(1, 1, "", "empty disk map").with_day(DAY));
+    };
+    if let Some(extra) = lines.next() {
+        return Err(extra.error(extra.text, "the disk map is expected on a single line"));
+    }
+
+    let diskmap = line.text.trim();
+    if let Some((offset, char)) = diskmap
+        .char_indices()
+        .find(|(_, char)| !char.is_ascii_digit())
+    {
+        return Err(line.error(
+            &diskmap[offset..offset + char.len_utf8()],
+            "expected a digit",
+        ));
+    }
+    Result::Ok(diskmap.to_string())
+}
+
 //region Part 1
 
 fn solve_part_1(input_data: &str) -> Result<String> {
@@ -204,37 +226,32 @@ fn solve_part_2(input_data: &str) -> Result<String> {
 }
 //endregion
 
-fn main() -> Result<()> {
-    start_day(DAY);
-
-    let (input_data, duration) = time_function!(get_input_data(DAY)?);
-    println!("Input data loaded in {}", pretty_duration(duration));
-
-    let (answer_part_1, part1_duration) = time_function!(solve_part_1(&input_data)?);
-    println!(
-        "Part 1: {} (solved in {})",
-        answer_part_1,
-        pretty_duration(part1_duration)
-    );
-    check_result(&answer_part_1, SOLUTION_PART_1);
-
-    let (answer_part_2, part2_duration) = time_function!(solve_part_2(&input_data)?);
-    println!(
-        "Part 2: {} (solved in {})",
-        answer_part_2,
-        pretty_duration(part2_duration)
-    );
-    check_result(&answer_part_2, SOLUTION_PART_2);
-
-    Ok(())
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: &'static str = DAY;
+
+    type Input = String;
+
+    fn parse_input(input_data: &str) -> Result<Self::Input> {
+        Ok(check_diskmap(input_data)?)
+    }
+
+    fn solve_part_1(input_data: &Self::Input) -> Result<String> {
+        solve_part_1(input_data)
+    }
+
+    fn solve_part_2(input_data: &Self::Input) -> Result<String> {
+        solve_part_2(input_data)
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::diskutil;
-    use crate::diskutil::AtomicBlock::{FileBlock, FreeBlock};
-    use crate::diskutil::DiskBlock::{File, FreeSpace};
-    use crate::diskutil::{compute_checksum, compute_checksum_block};
+    use crate::days::day_09::diskutil;
+    use crate::days::day_09::diskutil::AtomicBlock::{FileBlock, FreeBlock};
+    use crate::days::day_09::diskutil::DiskBlock::{File, FreeSpace};
+    use crate::days::day_09::diskutil::{compute_checksum, compute_checksum_block};
 
     #[test]
     fn should_load_disk() {
@@ -0,0 +1,347 @@
+use crate::days::day_10::topo::Map;
+use crate::Solution;
+use anyhow::*;
+use std::str::FromStr;
+
+const DAY: &str = "10";
+
+mod topo {
+    use crate::grid::Grid;
+    use crate::parse::ParseError;
+    use crate::position::Position;
+    use crate::search::{bfs, count_paths};
+    use anyhow::anyhow;
+    use std::str::FromStr;
+    const IMPASSABLE_TILE: i8 = -1;
+    const MAX_HEIGHT: i8 = 9;
+
+    #[derive(Debug, PartialEq)]
+    pub struct Map {
+        pub heights: Grid<i8>,
+    }
+
+    impl FromStr for Map {
+        type Err = ParseError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            let heights = Grid::parse(s, |c| match c {
+                '.' => Ok(IMPASSABLE_TILE),
+                _ => c
+                    .to_digit(10)
+                    .map(|height| height as i8)
+                    .ok_or_else(|| anyhow!("expected a height or '.'")),
+            })
+            .map_err(|error| error.with_day(super::DAY))?;
+
+            Ok(Map { heights })
+        }
+    }
+
+    impl Map {
+        pub fn get_trailheads(&self) -> Vec<Position> {
+            self.heights.find_all(|height| *height == 0)
+        }
+
+        // Uphill steps of exactly one height
+        fn trail_steps(&self, position: &Position) -> impl Iterator<Item = Position> + '_ {
+            let next_height = self.heights[*position] + 1;
+            self.heights
+                .neighbours_4(*position)
+                .filter(move |neighbour| self.heights[*neighbour] == next_height)
+        }
+
+        fn find_trail_targets(&self, position: Position) -> Vec<Position> {
+            bfs(position, |position| self.trail_steps(position))
+                .order
+                .into_iter()
+                .filter(|position| self.heights[*position] == MAX_HEIGHT)
+                .collect()
+        }
+
+        pub fn get_trailhead_score(&self, position: Position) -> usize {
+            match self.heights.get(position) {
+                Some(0) => self.find_trail_targets(position).len(),
+                _ => 0,
+            }
+        }
+
+        fn find_trail_rating(&self, position: Position) -> usize {
+            count_paths(
+                position,
+                |position| self.trail_steps(position),
+                |position| self.heights[*position] == MAX_HEIGHT,
+            )
+        }
+
+        pub fn get_trailhead_rating(&self, position: Position) -> usize {
+            match self.heights.get(position) {
+                Some(0) => self.find_trail_rating(position),
+                _ => 0,
+            }
+        }
+    }
+}
+
+fn compute_trailhead_scores_sum(map: &Map) -> usize {
+    map.get_trailheads()
+        .iter()
+        .map(|position| map.get_trailhead_score(*position))
+        .sum()
+}
+
+fn compute_trailhead_rating_sum(map: &Map) -> usize {
+    let sum: usize = map
+        .get_trailheads()
+        .iter()
+        .map(|position| map.get_trailhead_rating(*position))
+        .sum();
+
+    sum
+}
+
+//region Part 1
+
+fn solve_part_1(map: &Map) -> Result<String> {
+    Ok(format!("{}", compute_trailhead_scores_sum(map)))
+}
+//endregion
+
+//region Part 2
+
+fn solve_part_2(map: &Map) -> Result<String> {
+    Ok(format!("{}", compute_trailhead_rating_sum(map)))
+}
+//endregion
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: &'static str = DAY;
+
+    type Input = Map;
+
+    fn parse_input(input_data: &str) -> Result<Self::Input> {
+        Ok(Map::from_str(input_data)?)
+    }
+
+    fn solve_part_1(map: &Self::Input) -> Result<String> {
+        solve_part_1(map)
+    }
+
+    fn solve_part_2(map: &Self::Input) -> Result<String> {
+        solve_part_2(map)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::days::day_10::topo::Map;
+    use crate::days::day_10::{compute_trailhead_rating_sum, compute_trailhead_scores_sum};
+    use crate::grid::Grid;
+    use crate::position::Position;
+    use std::str::FromStr;
+
+    #[test]
+    fn should_build_map() {
+        let expected = Map {
+            heights: Grid::from_cells(
+                7,
+                7,
+                [
+                    [-1, -1, -1, 0, -1, -1, -1],
+                    [-1, -1, -1, 1, -1, -1, -1],
+                    [-1, -1, -1, 2, -1, -1, -1],
+                    [6, 5, 4, 3, 4, 5, 6],
+                    [7, -1, -1, -1, -1, -1, 7],
+                    [8, -1, -1, -1, -1, -1, 8],
+                    [9, -1, -1, -1, -1, -1, 9],
+                ]
+                .concat(),
+            )
+            .unwrap(),
+        };
+
+        let input_data = "...0...
+...1...
+...2...
+6543456
+7.....7
+8.....8
+9.....9";
+
+        let map = Map::from_str(input_data).unwrap();
+
+        assert_eq!(expected, map);
+    }
+
+    #[test]
+    fn should_get_trailheads() {
+        let expected = [Position::new(0, 1), Position::new(6, 5)];
+
+        let map = Map::from_str(
+            "10..9..
+2...8..
+3...7..
+4567654
+...8..3
+...9..2
+.....01",
+        )
+        .unwrap();
+
+        assert_eq!(expected, *map.get_trailheads())
+    }
+
+    #[test]
+    fn should_get_trailhead_score() {
+        let expected = 2;
+
+        let map = Map::from_str(
+            "...0...
+...1...
+...2...
+6543456
+7.....7
+8.....8
+9.....9",
+        )
+        .unwrap();
+
+        assert_eq!(expected, map.get_trailhead_score(Position::new(0, 3)));
+    }
+
+    #[test]
+    fn should_get_trailhead_score_multiple() {
+        let expected = 4;
+
+        let map = Map::from_str(
+            "..90..9
+...1.98
+...2..7
+6543456
+765.987
+876....
+987....",
+        )
+        .unwrap();
+
+        assert_eq!(expected, map.get_trailhead_score(Position::new(0, 3)));
+    }
+
+    #[test]
+    fn should_get_trailhead_scores_one_trailhead() {
+        let expected = 2;
+        let map = Map::from_str(
+            "...0...
+...1...
+...2...
+6543456
+7.....7
+8.....8
+9.....9",
+        )
+        .unwrap();
+        assert_eq!(expected, compute_trailhead_scores_sum(&map));
+    }
+
+    #[test]
+    fn should_get_trailhead_scores_two_trailheads() {
+        let expected = 3;
+        let map = Map::from_str(
+            "10..9..
+2...8..
+3...7..
+4567654
+...8..3
+...9..2
+.....01",
+        )
+        .unwrap();
+        assert_eq!(expected, compute_trailhead_scores_sum(&map));
+    }
+
+    #[test]
+    fn should_get_trailhead_scores() {
+        let expected = 36;
+        let map = Map::from_str(
+            "89010123
+    78121874
+    87430965
+    96549874
+    45678903
+    32019012
+    01329801
+    10456732",
+        )
+        .unwrap();
+        assert_eq!(expected, compute_trailhead_scores_sum(&map));
+    }
+
+    #[test]
+    fn should_get_trailhead_rating_simple() {
+        let expected = 3;
+        let map = Map::from_str(
+            ".....0.
+..4321.
+..5..2.
+..6543.
+..7..4.
+..8765.
+..9....",
+        )
+        .unwrap();
+
+        assert_eq!(expected, map.get_trailhead_rating(Position::new(0, 5)));
+    }
+
+    #[test]
+    fn should_get_trailhead_rating_medium() {
+        let expected = 13;
+        let map = Map::from_str(
+            "..90..9
+...1.98
+...2..7
+6543456
+765.987
+876....
+987....",
+        )
+        .unwrap();
+
+        assert_eq!(expected, map.get_trailhead_rating(Position::new(0, 3)));
+    }
+
+    #[test]
+    fn should_get_trailhead_rating_complex() {
+        let expected = 227;
+        let map = Map::from_str(
+            "012345
+123456
+234567
+345678
+4.6789
+56789.",
+        )
+        .unwrap();
+
+        assert_eq!(expected, map.get_trailhead_rating(Position::new(0, 0)));
+    }
+
+    #[test]
+    fn should_get_trailhead_ratings_example() {
+        let expected = 81;
+        let map = Map::from_str(
+            "89010123
+78121874
+87430965
+96549874
+45678903
+32019012
+01329801
+10456732",
+        )
+        .unwrap();
+
+        assert_eq!(expected, compute_trailhead_rating_sum(&map));
+    }
+}
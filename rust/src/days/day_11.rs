@@ -1,28 +1,30 @@
-use crate::stone::Stoneset;
+use crate::days::day_11::stone::Stoneset;
+use crate::Solution;
 use anyhow::*;
-use aoc2024::*;
 use std::str::FromStr;
 
 const DAY: &str = "11";
-const SOLUTION_PART_1: &str = "233875";
-const SOLUTION_PART_2: &str = "277444936413293";
 
 mod stone {
+    use crate::parse::{input_lines, ParseError};
     use std::collections::HashMap;
     use std::str::FromStr;
 
+    #[derive(Clone)]
     pub struct Stoneset {
         pub stones: HashMap<usize, usize>,
     }
 
     impl FromStr for Stoneset {
-        type Err = anyhow::Error;
+        type Err = ParseError;
 
         fn from_str(input: &str) -> Result<Self, Self::Err> {
             let mut stones: HashMap<usize, usize> = HashMap::new();
 
-            for stone in input.split_whitespace() {
-                stones.insert(stone.parse()?, 1);
+            for line in input_lines(super::DAY, input) {
+                for stone in line.text.split_whitespace() {
+                    stones.insert(line.parse(stone)?, 1);
+                }
             }
 
             Ok(Self { stones })
@@ -69,8 +71,8 @@ mod stone {
 
 //region Part 1
 
-fn solve_part_1(input_data: &str) -> Result<String> {
-    let mut stoneset = Stoneset::from_str(input_data)?;
+fn solve_part_1(stoneset: &Stoneset) -> Result<String> {
+    let mut stoneset = stoneset.clone();
 
     for _ in 0..25 {
         stoneset.blink();
@@ -83,8 +85,8 @@ fn solve_part_1(input_data: &str) -> Result<String> {
 
 //region Part 2
 
-fn solve_part_2(input_data: &str) -> Result<String> {
-    let mut stoneset = Stoneset::from_str(input_data)?;
+fn solve_part_2(stoneset: &Stoneset) -> Result<String> {
+    let mut stoneset = stoneset.clone();
 
     for _ in 0..75 {
         stoneset.blink();
@@ -95,34 +97,29 @@ fn solve_part_2(input_data: &str) -> Result<String> {
 }
 //endregion
 
-fn main() -> Result<()> {
-    start_day(DAY);
-
-    let (input_data, duration) = time_function!(get_input_data(DAY)?);
-    println!("Input data loaded in {}", pretty_duration(duration));
-
-    let (answer_part_1, part1_duration) = time_function!(solve_part_1(&input_data)?);
-    println!(
-        "Part 1: {} (solved in {})",
-        answer_part_1,
-        pretty_duration(part1_duration)
-    );
-    check_result(&answer_part_1, SOLUTION_PART_1);
-
-    let (answer_part_2, part2_duration) = time_function!(solve_part_2(&input_data)?);
-    println!(
-        "Part 2: {} (solved in {})",
-        answer_part_2,
-        pretty_duration(part2_duration)
-    );
-    check_result(&answer_part_2, SOLUTION_PART_2);
-
-    Ok(())
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: &'static str = DAY;
+
+    type Input = Stoneset;
+
+    fn parse_input(input_data: &str) -> Result<Self::Input> {
+        Ok(Stoneset::from_str(input_data)?)
+    }
+
+    fn solve_part_1(stoneset: &Self::Input) -> Result<String> {
+        solve_part_1(stoneset)
+    }
+
+    fn solve_part_2(stoneset: &Self::Input) -> Result<String> {
+        solve_part_2(stoneset)
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::stone::Stoneset;
+    use crate::days::day_11::stone::Stoneset;
     use std::collections::HashMap;
     use std::str::FromStr;
 
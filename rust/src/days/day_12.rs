@@ -0,0 +1,324 @@
+use crate::days::day_12::garden::Garden;
+use crate::Solution;
+use anyhow::*;
+use std::str::FromStr;
+
+const DAY: &str = "12";
+
+mod garden {
+    use crate::grid::Grid;
+    use crate::parse::ParseError;
+    use crate::position::Position;
+    use crate::search::bfs;
+    use std::collections::HashMap;
+    use std::fmt::Display;
+    use std::str::FromStr;
+
+    pub struct Garden {
+        tiles: Grid<char>,
+        tiles_regions: Grid<Option<usize>>,
+        regions: HashMap<usize, Vec<Position>>,
+    }
+
+    impl Garden {
+        fn populate_region(&mut self, region_id: usize, seed_position: &Position) {
+            let seed_char = self.tiles[*seed_position];
+
+            let region = bfs(*seed_position, |position| {
+                self.tiles
+                    .neighbours_4(*position)
+                    .filter(|neighbour| self.tiles[*neighbour] == seed_char)
+                    .collect::<Vec<_>>()
+            })
+            .order;
+
+            for position in &region {
+                self.tiles_regions[*position] = Some(region_id);
+            }
+            self.regions.insert(region_id, region);
+        }
+
+        fn identify_regions(&mut self) {
+            self.tiles_regions = self.tiles.map(|_| None);
+            self.regions = HashMap::new();
+
+            let mut current_region_id = 0usize;
+            for position in self.tiles.positions().collect::<Vec<_>>() {
+                if self.tiles_regions[position].is_none() {
+                    self.populate_region(current_region_id, &position);
+                    current_region_id += 1;
+                }
+            }
+        }
+
+        fn compute_price_for_region(&self, region_id: usize) -> usize {
+            let mut perimeter = 0usize;
+            let member_positions = self.regions.get(&region_id).unwrap();
+            let area = member_positions.len();
+            let region_char = self.tiles[*member_positions.first().unwrap()];
+
+            for position in member_positions {
+                for neighbour in position.neighbours_4() {
+                    if self.tiles.get(neighbour) != Some(&region_char) {
+                        perimeter += 1;
+                    }
+                }
+            }
+
+            perimeter * area
+        }
+
+        pub fn compute_fence_price_per_region(&self) -> HashMap<usize, usize> {
+            self.regions
+                .keys()
+                .map(|region_id| (*region_id, self.compute_price_for_region(*region_id)))
+                .collect()
+        }
+
+        fn compute_discounted_price_for_region(&self, region_id: usize) -> usize {
+            let mut total_edges = 0usize;
+
+            let member_positions = self.regions.get(&region_id).unwrap();
+            let area = member_positions.len();
+            let region_char = self.tiles[*member_positions.first().unwrap()];
+
+            let is_from_same_region =
+                |position: Position| self.tiles.get(position) == Some(&region_char);
+
+            for position in member_positions {
+                let neighbours = position.neighbours_8();
+
+                let mut n_edges = 0usize;
+                for edge in 0..4 {
+                    let edge_neighbour_1 = neighbours[edge * 2];
+                    let edge_neighbour_2 = neighbours[edge * 2 + 1];
+                    let edge_neighbour_3 = neighbours[(edge * 2 + 2) % 8];
+
+                    let mut n_consecutive_foreign_neighbours = 0usize;
+                    if !is_from_same_region(edge_neighbour_1) {
+                        n_consecutive_foreign_neighbours += 1;
+                    }
+                    if !is_from_same_region(edge_neighbour_2) {
+                        n_consecutive_foreign_neighbours += 1;
+                    } else {
+                        n_consecutive_foreign_neighbours = 0;
+                    }
+                    if !is_from_same_region(edge_neighbour_3) {
+                        n_consecutive_foreign_neighbours += 1;
+                    } else {
+                        n_consecutive_foreign_neighbours = 0;
+                    }
+
+                    if n_consecutive_foreign_neighbours % 2 == 1 {
+                        n_edges += 1;
+                    }
+                }
+
+                total_edges += n_edges;
+            }
+
+            area * total_edges
+        }
+
+        pub fn compute_discounted_price_per_region(&self) -> HashMap<usize, usize> {
+            self.regions
+                .keys()
+                .map(|region_id| {
+                    (
+                        *region_id,
+                        self.compute_discounted_price_for_region(*region_id),
+                    )
+                })
+                .collect()
+        }
+    }
+
+    impl FromStr for Garden {
+        type Err = ParseError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            let tiles: Grid<char> = s
+                .parse()
+                .map_err(|error: ParseError| error.with_day(super::DAY))?;
+
+            let mut garden = Garden {
+                tiles_regions: tiles.map(|_| None),
+                tiles,
+                regions: HashMap::new(),
+            };
+            garden.identify_regions();
+            Ok(garden)
+        }
+    }
+
+    impl Display for Garden {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::result::Result<(), std::fmt::Error> {
+            write!(f, "{}", self.tiles)
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        const SMALL_EXAMPLE: &str = "AAAA
+BBCD
+BBCC
+EEEC";
+        const BIGGER_EXAMPLE: &str = "RRRRIICCFF
+RRRRIICCCF
+VVRRRCCFFF
+VVRCCCJFFF
+VVVVCJJCFE
+VVIVCCJJEE
+VVIIICJJEE
+MIIIIIJJEE
+MIIISIJEEE
+MMMISSJEEE";
+
+        #[test]
+        fn should_load_from_str() {
+            let expected_width = 4usize;
+            let expected_height = 4usize;
+            let expected_tiles: Vec<char> = "AAAABBCDBBCCEEEC".chars().collect();
+
+            let garden = Garden::from_str(SMALL_EXAMPLE).unwrap();
+
+            assert_eq!(expected_width, garden.tiles.width());
+            assert_eq!(expected_height, garden.tiles.height());
+            assert_eq!(
+                expected_tiles,
+                garden.tiles.iter().map(|(_, ch)| *ch).collect::<Vec<_>>()
+            );
+            assert_eq!(SMALL_EXAMPLE, format!("{}", garden));
+        }
+
+        #[test]
+        fn should_identify_regions() {
+            let expected_regions: HashMap<usize, Vec<Position>> = HashMap::from([
+                (0usize, vec![(0, 0), (0, 1), (0, 2), (0, 3)]),
+                (1, vec![(1, 0), (1, 1), (2, 0), (2, 1)]),
+                (2, vec![(1, 2), (2, 2), (2, 3), (3, 3)]),
+                (3, vec![(1, 3)]),
+                (4, vec![(3, 0), (3, 1), (3, 2)]),
+            ])
+            .into_iter()
+            .map(|(key, positions)| {
+                let positions = positions
+                    .into_iter()
+                    .map(|(row, col)| Position::new(row, col))
+                    .collect();
+                (key, positions)
+            })
+            .collect();
+
+            let garden = Garden::from_str(SMALL_EXAMPLE).unwrap();
+
+            for (key, expected_value) in expected_regions.iter() {
+                let region = &garden.regions[key];
+                assert_eq!(
+                    expected_value.len(),
+                    region.len(),
+                    "region #{} has size {} (expected: {})",
+                    key,
+                    region.len(),
+                    expected_value.len()
+                );
+
+                for value in expected_value.iter() {
+                    assert!(
+                        region.contains(value),
+                        "region #{:?} does not contain {:?}",
+                        key,
+                        value
+                    );
+                }
+            }
+        }
+
+        #[test]
+        fn should_compute_fence_price_per_region() {
+            let expected_prices: HashMap<usize, usize> =
+                HashMap::from([(0, 40), (1, 32), (2, 40), (3, 4), (4, 24)]);
+
+            let garden = Garden::from_str(SMALL_EXAMPLE).unwrap();
+
+            let prices = garden.compute_fence_price_per_region();
+
+            for (key, expected_value) in expected_prices.iter() {
+                let price = prices[key];
+
+                assert_eq!(
+                    expected_value, &price,
+                    "price #{} does not match expected price {} (actual: {})",
+                    key, expected_value, price
+                );
+            }
+        }
+
+        #[test]
+        fn should_compute_fence_price_bigger_example() {
+            let expected_sum = 1930;
+
+            let prices = Garden::from_str(BIGGER_EXAMPLE)
+                .unwrap()
+                .compute_fence_price_per_region();
+            let sum = prices.values().sum::<usize>();
+            assert_eq!(expected_sum, sum);
+        }
+
+        #[test]
+        fn should_compute_discounted_price_for_small_example() {
+            let expected_sum = 80usize;
+            let prices = Garden::from_str(SMALL_EXAMPLE)
+                .unwrap()
+                .compute_discounted_price_per_region();
+            let sum = prices.values().sum::<usize>();
+            assert_eq!(expected_sum, sum);
+        }
+
+        #[test]
+        fn should_compute_discounted_price_for_bigger_example() {
+            let expected_sum = 1206usize;
+            let prices = Garden::from_str(BIGGER_EXAMPLE)
+                .unwrap()
+                .compute_discounted_price_per_region();
+            let sum = prices.values().sum::<usize>();
+            assert_eq!(expected_sum, sum);
+        }
+    }
+}
+//region Part 1
+
+fn solve_part_1(garden: &Garden) -> Result<String> {
+    let prices = garden.compute_fence_price_per_region();
+    Ok(format!("{}", prices.values().sum::<usize>()))
+}
+//endregion
+
+//region Part 2
+
+fn solve_part_2(garden: &Garden) -> Result<String> {
+    let prices = garden.compute_discounted_price_per_region();
+    Ok(format!("{}", prices.values().sum::<usize>()))
+}
+//endregion
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: &'static str = DAY;
+
+    type Input = Garden;
+
+    fn parse_input(input_data: &str) -> Result<Self::Input> {
+        Ok(Garden::from_str(input_data)?)
+    }
+
+    fn solve_part_1(garden: &Self::Input) -> Result<String> {
+        solve_part_1(garden)
+    }
+
+    fn solve_part_2(garden: &Self::Input) -> Result<String> {
+        solve_part_2(garden)
+    }
+}
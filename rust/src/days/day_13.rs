@@ -1,13 +1,11 @@
-use crate::clawmachine::{load_clawmachines, ClawMachine};
+use crate::days::day_13::clawmachine::{load_clawmachines, ClawMachine};
+use crate::Solution;
 use anyhow::*;
-use aoc2024::*;
 
 const DAY: &str = "13";
-const SOLUTION_PART_1: &str = "25629";
-const SOLUTION_PART_2: &str = "107487112929999";
 
 mod clawmachine {
-    use itertools::Itertools;
+    use crate::parse::{blocks, InputLine, ParseError};
     const BIAS: isize = 10000000000000isize;
 
     #[derive(Debug, PartialEq)]
@@ -60,6 +58,14 @@ mod clawmachine {
             Some(ClawMachineSolution { nmoves_a, nmoves_b })
         }
 
+        // Same machine with the prize moved by the unit conversion error of part 2
+        pub fn biased(&self) -> ClawMachine {
+            ClawMachine {
+                target: (self.target.0 + BIAS, self.target.1 + BIAS),
+                ..*self
+            }
+        }
+
         pub fn compute_cost(&self) -> Option<isize> {
             let solution = self.solve(false)?;
 
@@ -73,49 +79,44 @@ mod clawmachine {
         }
     }
 
-    fn parse_button(line: &str) -> (isize, isize) {
-        let split_1: Vec<&str> = line.split(",").collect::<Vec<_>>();
-        let left = split_1[0].split("+").collect::<Vec<_>>()[1];
+    // Parses "<prefix><x><separator><y>", e.g. "Button A: X+94, Y+34"
+    fn parse_pair(
+        line: &InputLine,
+        prefix: &str,
+        separator: &str,
+    ) -> Result<(isize, isize), ParseError> {
+        let values = line.strip_prefix(line.text.trim_end(), prefix)?;
+        let [x, y] = line.integer_array_separated_by(values, &[separator])?;
 
-        let right = &split_1[1].trim()[2..];
-
-        (left.parse().unwrap(), right.parse().unwrap())
+        Ok((x, y))
     }
 
-    fn parse_target(line: &str) -> (isize, isize) {
-        let split_1: Vec<&str> = line.split(",").collect::<Vec<_>>();
-        let left = split_1[0].split("=").collect::<Vec<_>>()[1];
-        let right = split_1[1].split("=").collect::<Vec<_>>()[1];
-
-        (left.parse().unwrap(), right.parse().unwrap())
-    }
-    pub fn load_clawmachines(input: &str, biased: bool) -> Vec<ClawMachine> {
+    pub fn load_clawmachines(input: &str, biased: bool) -> Result<Vec<ClawMachine>, ParseError> {
         let mut clawmachines = Vec::new();
 
-        for lines in &input.lines().chunks(4) {
-            let mut input_a = (0, 0);
-            let mut input_b = (0, 0);
-            let mut target = (0, 0);
-            for (num, line) in lines.enumerate() {
-                match num {
-                    0 => input_a = parse_button(line),
-                    1 => input_b = parse_button(line),
-                    2 => target = parse_target(line),
-                    _ => {}
-                }
-            }
-
-            if biased {
-                target = (target.0 + BIAS, target.1 + BIAS);
-            }
+        for machine_lines in blocks(super::DAY, input) {
+            let [button_a, button_b, prize] = machine_lines[..] else {
+                let last_line = machine_lines[machine_lines.len() - 1];
+                let message = "expected two buttons and a prize per claw machine";
+                return Err(if machine_lines.len() < 3 {
+                    last_line.error_at_end(message)
+                } else {
+                    last_line.error(last_line.text, message)
+                });
+            };
+            let clawmachine = ClawMachine {
+                input_a: parse_pair(&button_a, "Button A: X+", ", Y+")?,
+                input_b: parse_pair(&button_b, "Button B: X+", ", Y+")?,
+                target: parse_pair(&prize, "Prize: X=", ", Y=")?,
+            };
 
-            clawmachines.push(ClawMachine {
-                input_a,
-                input_b,
-                target,
+            clawmachines.push(if biased {
+                clawmachine.biased()
+            } else {
+                clawmachine
             });
         }
-        clawmachines
+        Ok(clawmachines)
     }
 
     #[cfg(test)]
@@ -201,17 +202,32 @@ Prize: X=8400, Y=5400
 Button A: X+26, Y+66
 Button B: X+67, Y+21
 Prize: X=12748, Y=12176";
-            let result = load_clawmachines(input, false);
+            let result = load_clawmachines(input, false).unwrap();
 
             assert_eq!(expected, result);
         }
+
+        #[test]
+        fn should_reject_malformed_clawmachines() {
+            let error = load_clawmachines(
+                "Button A: X+94, Y+34\r\nButton B: X+22, Y=67\r\nPrize: X=8400, Y=5400\r\n",
+                false,
+            )
+            .unwrap_err();
+            assert_eq!((2, 13), (error.line, error.column));
+            assert_eq!("22,", error.snippet);
+
+            let error = load_clawmachines("Button A: X+94, Y+34\nButton B: X+22, Y+67\n", false)
+                .unwrap_err();
+            assert_eq!((2, 21), (error.line, error.column));
+            assert_eq!("", error.snippet);
+        }
     }
 }
 
 //region Part 1
 
-fn solve_part_1(input_data: &str) -> Result<String> {
-    let clawmachines = load_clawmachines(input_data, false);
+fn solve_part_1(clawmachines: &[ClawMachine]) -> Result<String> {
     let result: isize = clawmachines
         .iter()
         .map(ClawMachine::compute_cost)
@@ -224,11 +240,10 @@ fn solve_part_1(input_data: &str) -> Result<String> {
 
 //region Part 2
 
-fn solve_part_2(input_data: &str) -> Result<String> {
-    let clawmachines = load_clawmachines(input_data, true);
+fn solve_part_2(clawmachines: &[ClawMachine]) -> Result<String> {
     let result: isize = clawmachines
         .iter()
-        .map(ClawMachine::compute_biased_cost)
+        .map(|clawmachine| clawmachine.biased().compute_biased_cost())
         .map(|solution| solution.unwrap_or(0))
         .sum();
 
@@ -236,34 +251,29 @@ fn solve_part_2(input_data: &str) -> Result<String> {
 }
 //endregion
 
-fn main() -> Result<()> {
-    start_day(DAY);
-
-    let (input_data, duration) = time_function!(get_input_data(DAY)?);
-    println!("Input data loaded in {}", pretty_duration(duration));
-
-    let (answer_part_1, part1_duration) = time_function!(solve_part_1(&input_data)?);
-    println!(
-        "Part 1: {} (solved in {})",
-        answer_part_1,
-        pretty_duration(part1_duration)
-    );
-    check_result(&answer_part_1, SOLUTION_PART_1);
-
-    let (answer_part_2, part2_duration) = time_function!(solve_part_2(&input_data)?);
-    println!(
-        "Part 2: {} (solved in {})",
-        answer_part_2,
-        pretty_duration(part2_duration)
-    );
-    check_result(&answer_part_2, SOLUTION_PART_2);
-
-    Ok(())
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: &'static str = DAY;
+
+    type Input = Vec<ClawMachine>;
+
+    fn parse_input(input_data: &str) -> Result<Self::Input> {
+        Ok(load_clawmachines(input_data, false)?)
+    }
+
+    fn solve_part_1(clawmachines: &Self::Input) -> Result<String> {
+        solve_part_1(clawmachines)
+    }
+
+    fn solve_part_2(clawmachines: &Self::Input) -> Result<String> {
+        solve_part_2(clawmachines)
+    }
 }
 
 #[cfg(test)]
 mod test_day_13 {
-    use crate::clawmachine::{load_clawmachines, ClawMachine};
+    use crate::days::day_13::clawmachine::{load_clawmachines, ClawMachine};
     #[test]
     fn should_solve_example() {
         let expected = 480;
@@ -285,7 +295,8 @@ Button A: X+69, Y+23
 Button B: X+27, Y+71
 Prize: X=18641, Y=10279",
             false,
-        );
+        )
+        .unwrap();
 
         let result: isize = clawmachines
             .iter()
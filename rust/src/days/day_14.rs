@@ -0,0 +1,940 @@
+use crate::days::day_14::bathroom::Bathroom;
+use crate::days::day_14::detectors::Detector;
+use crate::grid::Grid;
+use crate::image::Palette;
+use crate::simulation::Simulation;
+use crate::{DayOptions, Part, Solution};
+use anyhow::*;
+
+const DAY: &str = "14";
+
+mod bathroom {
+    use crate::grid::Grid;
+    use crate::image::Palette;
+    use crate::parse::{input_lines, InputLine, ParseError};
+    use crate::position::Position;
+    use crate::simulation::Simulation;
+
+    #[derive(Debug, Clone)]
+    pub struct Bathroom {
+        pub robot_positions: Vec<Position>,
+        pub robot_velocities: Vec<Position>,
+        pub size_x: usize,
+        pub size_y: usize,
+        starting_positions: Vec<Position>,
+        time: usize,
+    }
+
+    const SIZE_KEY: &str = "size";
+    const PUZZLE_SIZE: Size = (101, 103);
+
+    fn gcd(a: usize, b: usize) -> usize {
+        if b == 0 {
+            a
+        } else {
+            gcd(b, a % b)
+        }
+    }
+
+    fn lcm(a: usize, b: usize) -> usize {
+        a / gcd(a, b) * b
+    }
+
+    // Coordinate along an axis of the given size after t seconds, without overflowing for any t
+    fn advance(start: isize, velocity: isize, t: usize, size: usize) -> isize {
+        let steps = (t % size) as isize;
+        (start + velocity.rem_euclid(size as isize) * steps).rem_euclid(size as isize)
+    }
+
+    // Number of seconds for the robots to all be back on their column (or row)
+    fn axis_period(velocities: impl Iterator<Item = isize>, size: usize) -> usize {
+        velocities.fold(1, |period, velocity| {
+            let velocity = velocity.rem_euclid(size as isize) as usize;
+            lcm(period, size / gcd(velocity, size))
+        })
+    }
+
+    // Coordinates are given as x,y: x is the column and y the row
+    fn parse_coordinates(line: &InputLine, key: &str) -> Result<Position, ParseError> {
+        let [x, y] = line.integer_array(line.value_of(line.text, key)?)?;
+
+        Ok(Position::new(y, x))
+    }
+
+    // A robot with its position and velocity, along with where it was defined
+    type Robot<'a> = (InputLine<'a>, Position, Position);
+
+    type Size = (usize, usize);
+
+    fn parse_robots(input: &str) -> Result<(Option<Size>, Vec<Robot<'_>>), ParseError> {
+        let mut size = None;
+        let mut robots = Vec::new();
+        for line in input_lines(super::DAY, input) {
+            if line.is_blank() {
+                continue;
+            }
+            if line.text.starts_with(SIZE_KEY) {
+                if size.is_some() || !robots.is_empty() {
+                    return Err(line.error(line.text, "the size must be the first line"));
+                }
+                let value = line.value_of(line.text, SIZE_KEY)?;
+                let [width, height]: [usize; 2] = line.integer_array(value)?;
+                if width == 0 || height == 0 {
+                    return Err(line.error(value, "the size can not be empty"));
+                }
+                size = Some((width, height));
+                continue;
+            }
+            robots.push((
+                line,
+                parse_coordinates(&line, "p")?,
+                parse_coordinates(&line, "v")?,
+            ));
+        }
+        Ok((size, robots))
+    }
+
+    impl Bathroom {
+        pub fn new(size_x: usize, size_y: usize) -> Bathroom {
+            Bathroom {
+                size_x,
+                size_y,
+                robot_velocities: Vec::new(),
+                robot_positions: Vec::new(),
+                starting_positions: Vec::new(),
+                time: 0,
+            }
+        }
+
+        // The bathroom size comes from an optional size=width,height first line, otherwise it is
+        // the 101x103 one of the puzzle
+        pub fn from_input(input: &str) -> Result<Bathroom, ParseError> {
+            let mut bathroom = Bathroom::new(0, 0);
+            bathroom.load_robots_from_str(input)?;
+            Ok(bathroom)
+        }
+
+        // A size line in the input overrides the current size, an empty bathroom gets the puzzle one
+        pub fn load_robots_from_str(&mut self, input: &str) -> Result<(), ParseError> {
+            let (size, robots) = parse_robots(input)?;
+            if let Some((size_x, size_y)) = size {
+                self.size_x = size_x;
+                self.size_y = size_y;
+            } else if self.size_x == 0 && self.size_y == 0 {
+                (self.size_x, self.size_y) = PUZZLE_SIZE;
+            }
+            self.add_robots(robots)
+        }
+
+        fn add_robots(&mut self, robots: Vec<Robot>) -> Result<(), ParseError> {
+            for (line, position, velocity) in robots {
+                if !(0..self.size_x as isize).contains(&position.col)
+                    || !(0..self.size_y as isize).contains(&position.row)
+                {
+                    return Err(line.error(
+                        line.text,
+                        format!(
+                            "robot outside of the {}x{} bathroom",
+                            self.size_x, self.size_y
+                        ),
+                    ));
+                }
+                self.robot_positions.push(position);
+                self.robot_velocities.push(velocity);
+            }
+            self.starting_positions = self.robot_positions.clone();
+            self.time = 0;
+            Ok(())
+        }
+
+        pub fn tick(&mut self) {
+            self.jump_to(self.time + 1);
+        }
+
+        pub fn jump_to(&mut self, t: usize) {
+            self.time = t;
+            self.robot_positions = self.positions_at(t);
+        }
+
+        pub fn positions_at(&self, t: usize) -> Vec<Position> {
+            self.starting_positions
+                .iter()
+                .zip(&self.robot_velocities)
+                .map(|(start, velocity)| {
+                    Position::new(
+                        advance(start.row, velocity.row, t, self.size_y),
+                        advance(start.col, velocity.col, t, self.size_x),
+                    )
+                })
+                .collect()
+        }
+
+        // Periods of the columns and of the rows, taken separately
+        pub fn axis_periods(&self) -> (usize, usize) {
+            (
+                axis_period(self.robot_velocities.iter().map(|v| v.col), self.size_x),
+                axis_period(self.robot_velocities.iter().map(|v| v.row), self.size_y),
+            )
+        }
+
+        // Number of seconds before the robots are all back to their start
+        pub fn period(&self) -> usize {
+            let (period_x, period_y) = self.axis_periods();
+            lcm(period_x, period_y)
+        }
+
+        fn get_quadrant_population(&self) -> [usize; 4] {
+            let mut n_in_first_quadrant = 0;
+            let mut n_in_second_quadrant = 0;
+            let mut n_in_third_quadrant = 0;
+            let mut n_in_fourth_quadrant = 0;
+
+            let middle_x = self.size_x as isize / 2;
+            let middle_y = self.size_y as isize / 2;
+
+            for Position { row: y, col: x } in &self.robot_positions {
+                if *x < middle_x && *y < middle_y {
+                    n_in_first_quadrant += 1;
+                }
+
+                if *x > middle_x && *y < middle_y {
+                    n_in_second_quadrant += 1;
+                }
+
+                if *x < middle_x && *y > middle_y {
+                    n_in_third_quadrant += 1;
+                }
+
+                if *x > middle_x && *y > middle_y {
+                    n_in_fourth_quadrant += 1;
+                }
+            }
+
+            [
+                n_in_first_quadrant,
+                n_in_second_quadrant,
+                n_in_third_quadrant,
+                n_in_fourth_quadrant,
+            ]
+        }
+
+        pub fn get_safety_factor(&self) -> usize {
+            let mut factor = 1;
+            for n in self.get_quadrant_population() {
+                factor *= n
+            }
+            factor
+        }
+
+        pub fn is_same_as_start(&self) -> bool {
+            self.robot_positions == self.starting_positions
+        }
+
+        pub fn occupancy(&self) -> Grid<bool> {
+            let mut occupancy = Grid::new(self.size_x, self.size_y, false);
+            for position in &self.robot_positions {
+                occupancy[*position] = true;
+            }
+            occupancy
+        }
+    }
+
+    // Runs until the robots are back to their starting positions
+    impl Simulation for Bathroom {
+        fn tick(&mut self) -> bool {
+            if self.time > 0 && self.is_same_as_start() {
+                return false;
+            }
+            Bathroom::tick(self);
+            true
+        }
+
+        fn render(&self) -> String {
+            format!(
+                "{}\n\nSafety factor: {}",
+                self.tiles(),
+                self.get_safety_factor()
+            )
+        }
+
+        // Shows the number of robots per tile, like the puzzle statement
+        fn tiles(&self) -> Grid<char> {
+            let mut counts = Grid::new(self.size_x, self.size_y, 0u32);
+            for position in &self.robot_positions {
+                counts[*position] += 1;
+            }
+            counts.map(|count| match count {
+                0 => '.',
+                1..=9 => char::from_digit(*count, 10).unwrap(),
+                _ => '+',
+            })
+        }
+
+        fn palette(&self) -> Palette {
+            Palette::new([60, 200, 80]).with('.', [16, 16, 32])
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use crate::days::day_14::bathroom::Bathroom;
+        use crate::position::Position;
+
+        fn to_xy(positions: &[Position]) -> Vec<(isize, isize)> {
+            positions
+                .iter()
+                .map(|position| (position.col, position.row))
+                .collect()
+        }
+
+        #[test]
+        fn should_load_robots() {
+            let expected_positions = vec![
+                (0, 4),
+                (6, 3),
+                (10, 3),
+                (2, 0),
+                (0, 0),
+                (3, 0),
+                (7, 6),
+                (3, 0),
+                (9, 3),
+                (7, 3),
+                (2, 4),
+                (9, 5),
+            ];
+            let expected_velocities = vec![
+                (3, -3),
+                (-1, -3),
+                (-1, 2),
+                (2, -1),
+                (1, 3),
+                (-2, -2),
+                (-1, -3),
+                (-1, -2),
+                (2, 3),
+                (-1, 2),
+                (2, -3),
+                (-3, -3),
+            ];
+
+            let mut bathroom = Bathroom::new(11, 7);
+
+            let input = "p=0,4 v=3,-3
+p=6,3 v=-1,-3
+p=10,3 v=-1,2
+p=2,0 v=2,-1
+p=0,0 v=1,3
+p=3,0 v=-2,-2
+p=7,6 v=-1,-3
+p=3,0 v=-1,-2
+p=9,3 v=2,3
+p=7,3 v=-1,2
+p=2,4 v=2,-3
+p=9,5 v=-3,-3";
+
+            bathroom.load_robots_from_str(input).unwrap();
+            assert_eq!(expected_positions, to_xy(&bathroom.robot_positions));
+            assert_eq!(expected_velocities, to_xy(&bathroom.robot_velocities));
+        }
+
+        #[test]
+        fn should_tick_simple() {
+            let expected_positions = vec![(4, 1)];
+
+            let mut bathroom = Bathroom::new(11, 7);
+
+            let input = "p=2,4 v=2,-3";
+
+            bathroom.load_robots_from_str(input).unwrap();
+
+            bathroom.tick();
+
+            assert_eq!(expected_positions, to_xy(&bathroom.robot_positions));
+        }
+
+        #[test]
+        fn should_tick_wrap_x() {
+            let expected_positions = vec![(10, 0), (0, 4)];
+
+            let mut bathroom = Bathroom::new(11, 7);
+
+            let input = "p=0,0 v=-1,0
+p=10,4 v=1,0";
+
+            bathroom.load_robots_from_str(input).unwrap();
+
+            bathroom.tick();
+
+            assert_eq!(expected_positions, to_xy(&bathroom.robot_positions));
+        }
+
+        #[test]
+        fn should_tick_wrap_y() {
+            let expected_positions = vec![(0, 6), (4, 0)];
+
+            let mut bathroom = Bathroom::new(11, 7);
+
+            let input = "p=0,0 v=0,-1
+p=4,6 v=0,1";
+
+            bathroom.load_robots_from_str(input).unwrap();
+
+            bathroom.tick();
+
+            assert_eq!(expected_positions, to_xy(&bathroom.robot_positions));
+        }
+
+        const EXAMPLE_ROBOTS: &str = "p=0,4 v=3,-3
+p=6,3 v=-1,-3
+p=10,3 v=-1,2
+p=2,0 v=2,-1
+p=0,0 v=1,3
+p=3,0 v=-2,-2
+p=7,6 v=-1,-3
+p=3,0 v=-1,-2
+p=9,3 v=2,3
+p=7,3 v=-1,2
+p=2,4 v=2,-3
+p=9,5 v=-3,-3";
+
+        #[test]
+        fn should_compute_positions_at_any_time() {
+            let input = format!("size=11,7\n{}", EXAMPLE_ROBOTS);
+            let mut bathroom = Bathroom::from_input(&input).unwrap();
+            let period = bathroom.period();
+            assert_eq!((11, 7), bathroom.axis_periods());
+            assert_eq!(77, period);
+
+            let later = bathroom.positions_at(1_000_000_000_000);
+            for _ in 0..1_000_000_000_000usize % period {
+                bathroom.tick();
+            }
+            assert_eq!(later, bathroom.robot_positions);
+            assert_eq!(bathroom.positions_at(0), bathroom.positions_at(period));
+        }
+
+        #[test]
+        fn should_find_the_period_of_each_axis() {
+            // Every robot moves by a multiple of 3 columns on a 12 wide bathroom
+            let bathroom = Bathroom::from_input("size=12,4\np=0,0 v=3,2\np=1,1 v=-6,0").unwrap();
+            assert_eq!((4, 2), bathroom.axis_periods());
+            assert_eq!(4, bathroom.period());
+        }
+
+        #[test]
+        fn should_read_the_size_from_the_input() {
+            let input = format!("size=11,7\n{}", EXAMPLE_ROBOTS);
+            let bathroom = Bathroom::from_input(&input).unwrap();
+            assert_eq!((11, 7), (bathroom.size_x, bathroom.size_y));
+            assert_eq!(12, bathroom.robot_positions.len());
+
+            // Without a size line, even robots that all fit in the example are in the puzzle bathroom
+            let bathroom = Bathroom::from_input(EXAMPLE_ROBOTS).unwrap();
+            assert_eq!((101, 103), (bathroom.size_x, bathroom.size_y));
+            let bathroom = Bathroom::from_input("p=0,0 v=1,1\np=50,20 v=-3,2").unwrap();
+            assert_eq!((101, 103), (bathroom.size_x, bathroom.size_y));
+            assert!(Bathroom::from_input("p=101,0 v=1,1").is_err());
+
+            let error = Bathroom::from_input("size=5,5\np=5,0 v=1,1").unwrap_err();
+            assert_eq!(
+                "Day 14, line 2, column 1: robot outside of the 5x5 bathroom (got \"p=5,0 v=1,1\")",
+                error.to_string()
+            );
+            let error = Bathroom::from_input("p=0,0 v=1,1\nsize=5,5").unwrap_err();
+            assert_eq!((2, 1), (error.line, error.column));
+            assert!(Bathroom::from_input("size=0,5").is_err());
+        }
+
+        #[test]
+        fn should_reject_malformed_robots() {
+            let error = Bathroom::from_input("size=11,7\np=1x,2 v=1,1").unwrap_err();
+            assert_eq!(
+                "Day 14, line 2, column 3: invalid value: expected an integer (got \"1x\")",
+                error.to_string()
+            );
+
+            let error = Bathroom::from_input("size=11,7\np=x0,4 v=1,1").unwrap_err();
+            assert_eq!(
+                (2, 3, "x0"),
+                (error.line, error.column, error.snippet.as_str())
+            );
+        }
+
+        #[test]
+        fn should_compute_safety_factor() {
+            let expected = 0;
+            let mut bathroom = Bathroom::new(11, 7);
+            bathroom
+                .load_robots_from_str(
+                    "p=0,4 v=3,-3
+p=6,3 v=-1,-3
+p=10,3 v=-1,2
+p=2,0 v=2,-1
+p=0,0 v=1,3
+p=3,0 v=-2,-2
+p=7,6 v=-1,-3
+p=3,0 v=-1,-2
+p=9,3 v=2,3
+p=7,3 v=-1,2
+p=2,4 v=2,-3
+p=9,5 v=-3,-3",
+                )
+                .unwrap();
+
+            let safety_factor = bathroom.get_safety_factor();
+            assert_eq!(expected, safety_factor);
+        }
+    }
+}
+// Ways of spotting the Christmas tree, each one ranks the ticks from the most to the least likely
+mod detectors {
+    use crate::days::day_14::bathroom::Bathroom;
+    use crate::grid::Grid;
+    use crate::position::Position;
+    use crate::search::bfs;
+
+    pub trait Detector {
+        fn name(&self) -> &'static str;
+
+        fn rank(&self, bathroom: &Bathroom) -> Vec<usize>;
+    }
+
+    pub fn all() -> Vec<Box<dyn Detector>> {
+        vec![
+            Box::new(AxisVariance),
+            Box::new(MinSafetyFactor),
+            Box::new(LongestRun),
+            Box::new(Entropy),
+            Box::new(LargestComponent),
+        ]
+    }
+
+    pub fn by_name(name: &str) -> Option<Box<dyn Detector>> {
+        all().into_iter().find(|detector| detector.name() == name)
+    }
+
+    pub fn names() -> Vec<&'static str> {
+        all().iter().map(|detector| detector.name()).collect()
+    }
+
+    // Scores every tick until the robots are back to their start, the lowest score first
+    fn rank_over_period(bathroom: &Bathroom, score: impl Fn(&Bathroom) -> f64) -> Vec<usize> {
+        let mut bathroom = bathroom.clone();
+        let mut scores = Vec::new();
+        for tick in 1..=bathroom.period() {
+            bathroom.jump_to(tick);
+            scores.push((tick, score(&bathroom)));
+        }
+        scores.sort_by(|(_, a), (_, b)| a.total_cmp(b));
+        scores.into_iter().map(|(tick, _)| tick).collect()
+    }
+
+    fn variance(values: impl Iterator<Item = isize> + Clone) -> f64 {
+        let count = values.clone().count() as f64;
+        let mean = values.clone().sum::<isize>() as f64 / count;
+        values
+            .map(|value| (value as f64 - mean).powi(2))
+            .sum::<f64>()
+            / count
+    }
+
+    // Smallest t >= 0 with t = a (mod m) and t = b (mod n), if any
+    pub fn crt(a: usize, m: usize, b: usize, n: usize) -> Option<usize> {
+        (0..n).map(|k| a + k * m).find(|t| t % n == b)
+    }
+
+    // The puzzle's original heuristic: robots gathered in one quadrant
+    pub struct MinSafetyFactor;
+
+    impl Detector for MinSafetyFactor {
+        fn name(&self) -> &'static str {
+            "safety factor"
+        }
+
+        fn rank(&self, bathroom: &Bathroom) -> Vec<usize> {
+            rank_over_period(bathroom, |bathroom| bathroom.get_safety_factor() as f64)
+        }
+    }
+
+    // Columns and rows repeat with their own periods: the tick where the robots are the most
+    // packed on each axis is found separately, then both are combined
+    pub struct AxisVariance;
+
+    const AXIS_CANDIDATES: usize = 3;
+
+    impl Detector for AxisVariance {
+        fn name(&self) -> &'static str {
+            "axis variance"
+        }
+
+        fn rank(&self, bathroom: &Bathroom) -> Vec<usize> {
+            let (period_x, period_y) = bathroom.axis_periods();
+            let mut variances_x = Vec::with_capacity(period_x);
+            let mut variances_y = Vec::with_capacity(period_y);
+            for tick in 0..period_x.max(period_y) {
+                let positions = bathroom.positions_at(tick);
+                if tick < period_x {
+                    variances_x.push((tick, variance(positions.iter().map(|p| p.col))));
+                }
+                if tick < period_y {
+                    variances_y.push((tick, variance(positions.iter().map(|p| p.row))));
+                }
+            }
+            variances_x.sort_by(|(_, a), (_, b)| a.total_cmp(b));
+            variances_y.sort_by(|(_, a), (_, b)| a.total_cmp(b));
+
+            let mut candidates = Vec::new();
+            for (tick_x, variance_x) in variances_x.iter().take(AXIS_CANDIDATES) {
+                for (tick_y, variance_y) in variances_y.iter().take(AXIS_CANDIDATES) {
+                    let tick = crt(*tick_x, period_x, *tick_y, period_y);
+                    if let Some(tick) = tick {
+                        // Like the other detectors, the start is only seen again after a period
+                        let tick = if tick == 0 { bathroom.period() } else { tick };
+                        candidates.push((tick, variance_x + variance_y));
+                    }
+                }
+            }
+            candidates.sort_by(|(_, a), (_, b)| a.total_cmp(b));
+            candidates.into_iter().map(|(tick, _)| tick).collect()
+        }
+    }
+
+    // The frame of the tree is made of long lines of robots
+    pub struct LongestRun;
+
+    impl Detector for LongestRun {
+        fn name(&self) -> &'static str {
+            "longest run"
+        }
+
+        fn rank(&self, bathroom: &Bathroom) -> Vec<usize> {
+            rank_over_period(bathroom, |bathroom| {
+                let occupancy = bathroom.occupancy();
+                let longest = occupancy
+                    .rows()
+                    .flat_map(|row| row.split(|occupied| !occupied).map(|run| run.len()).max())
+                    .max()
+                    .unwrap_or(0);
+                -(longest as f64)
+            })
+        }
+    }
+
+    const ENTROPY_BLOCK: usize = 5;
+
+    // A picture is less random than scattered robots: the robots spread over fewer blocks
+    pub struct Entropy;
+
+    impl Detector for Entropy {
+        fn name(&self) -> &'static str {
+            "entropy"
+        }
+
+        fn rank(&self, bathroom: &Bathroom) -> Vec<usize> {
+            let blocks_x = bathroom.size_x.div_ceil(ENTROPY_BLOCK);
+            let blocks_y = bathroom.size_y.div_ceil(ENTROPY_BLOCK);
+            rank_over_period(bathroom, |bathroom| {
+                let mut counts = Grid::new(blocks_x, blocks_y, 0usize);
+                for position in &bathroom.robot_positions {
+                    let block = Position::new(
+                        position.row / ENTROPY_BLOCK as isize,
+                        position.col / ENTROPY_BLOCK as isize,
+                    );
+                    counts[block] += 1;
+                }
+                let total = bathroom.robot_positions.len() as f64;
+                counts
+                    .iter()
+                    .filter(|(_, count)| **count > 0)
+                    .map(|(_, count)| {
+                        let probability = *count as f64 / total;
+                        -probability * probability.log2()
+                    })
+                    .sum()
+            })
+        }
+    }
+
+    // The robots of the tree touch each other
+    pub struct LargestComponent;
+
+    impl Detector for LargestComponent {
+        fn name(&self) -> &'static str {
+            "largest component"
+        }
+
+        fn rank(&self, bathroom: &Bathroom) -> Vec<usize> {
+            rank_over_period(bathroom, |bathroom| {
+                let occupancy = bathroom.occupancy();
+                let mut visited = Grid::new(occupancy.width(), occupancy.height(), false);
+                let mut largest = 0;
+                for start in occupancy.find_all(|occupied| *occupied) {
+                    if visited[start] {
+                        continue;
+                    }
+                    let component = bfs(start, |position| {
+                        occupancy
+                            .neighbours_4(*position)
+                            .filter(|neighbour| occupancy[*neighbour])
+                            .collect::<Vec<_>>()
+                    });
+                    for position in &component.order {
+                        visited[*position] = true;
+                    }
+                    largest = largest.max(component.order.len());
+                }
+                -(largest as f64)
+            })
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        // Robots filling a 5x5 square at the given tick, scattered the rest of the time
+        fn square_at_tick(tick: isize) -> Bathroom {
+            let mut input = String::new();
+            for i in 0..25isize {
+                let (vx, vy) = (i * 7 % 23 - 11, i * 5 % 19 - 9);
+                let (col, row) = (9 + i % 5, 7 + i / 5);
+                let (x, y) = (
+                    (col - tick * vx).rem_euclid(23),
+                    (row - tick * vy).rem_euclid(19),
+                );
+                input.push_str(&format!("p={},{} v={},{}\n", x, y, vx, vy));
+            }
+            let mut bathroom = Bathroom::new(23, 19);
+            bathroom.load_robots_from_str(&input).unwrap();
+            bathroom
+        }
+
+        #[test]
+        fn should_combine_axis_ticks() {
+            assert_eq!(Some(0), crt(0, 11, 0, 7));
+            assert_eq!(Some(3), crt(3, 11, 3, 7));
+            assert_eq!(Some(47), crt(3, 11, 5, 7));
+            assert_eq!(None, crt(1, 4, 0, 2));
+        }
+
+        #[test]
+        fn should_detect_the_square() {
+            let bathroom = square_at_tick(3);
+
+            for detector in all() {
+                let ranking = detector.rank(&bathroom);
+                if detector.name() == "safety factor" {
+                    assert!(ranking.contains(&3));
+                } else {
+                    assert_eq!(Some(&3), ranking.first(), "{}", detector.name());
+                }
+            }
+            assert!(by_name("astrology").is_none());
+        }
+
+        #[test]
+        fn should_rank_the_same_ticks_with_every_detector() {
+            // The square is back after a full period, the start itself is not an answer
+            let bathroom = square_at_tick(0);
+            let period = bathroom.period();
+
+            for detector in all() {
+                let ranking = detector.rank(&bathroom);
+                assert!(
+                    ranking.iter().all(|tick| (1..=period).contains(tick)),
+                    "{}",
+                    detector.name()
+                );
+                assert!(ranking.contains(&period), "{}", detector.name());
+            }
+            // Lines of 5 robots show up by chance, only the square is this packed and compact
+            for name in ["axis variance", "entropy", "largest component"] {
+                let ranking = by_name(name).unwrap().rank(&bathroom);
+                assert_eq!(Some(&period), ranking.first(), "{}", name);
+            }
+        }
+    }
+}
+
+//region Part 1
+
+fn solve_part_1(bathroom: &Bathroom) -> Result<String> {
+    let mut bathroom = bathroom.clone();
+    bathroom.jump_to(100);
+
+    Ok(format!("{}", bathroom.get_safety_factor()))
+}
+//endregion
+
+//region Part 2
+
+// Only needs width + height ticks, unlike the detectors going through the whole period
+const DEFAULT_DETECTOR: &str = "axis variance";
+
+fn find_tree(bathroom: &Bathroom, detector: &dyn Detector) -> Result<usize> {
+    detector
+        .rank(bathroom)
+        .into_iter()
+        .next()
+        .ok_or_else(|| anyhow!("The {} detector found no candidate", detector.name()))
+}
+
+fn solve_part_2(watch: &BathroomWatch) -> Result<String> {
+    let tick = find_tree(&watch.bathroom, watch.detector.as_ref())?;
+
+    Ok(format!("{}", tick))
+}
+//endregion
+
+// The bathroom, and the detector looking for the Christmas tree in it
+pub struct BathroomWatch {
+    bathroom: Bathroom,
+    detector: Box<dyn Detector>,
+}
+
+fn detector_by_name(name: &str) -> Result<Box<dyn Detector>> {
+    detectors::by_name(name).ok_or_else(|| {
+        anyhow!(
+            "Unknown detector: {} (expected one of: {})",
+            name,
+            detectors::names().join(", ")
+        )
+    })
+}
+
+// The robots of part 2, along with the tick where the detector expects the tree
+struct TreeHunt {
+    bathroom: Bathroom,
+    detector: &'static str,
+    tree: Option<usize>,
+}
+
+impl Simulation for TreeHunt {
+    fn tick(&mut self) -> bool {
+        Simulation::tick(&mut self.bathroom)
+    }
+
+    fn render(&self) -> String {
+        let tree = match self.tree {
+            Some(tick) => format!("tick {}", tick),
+            None => "none".to_string(),
+        };
+        format!(
+            "{}\nTree candidate of the {} detector: {}",
+            self.bathroom.render(),
+            self.detector,
+            tree
+        )
+    }
+
+    fn tiles(&self) -> Grid<char> {
+        self.bathroom.tiles()
+    }
+
+    fn palette(&self) -> Palette {
+        self.bathroom.palette()
+    }
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: &'static str = DAY;
+
+    type Input = BathroomWatch;
+
+    fn parse_input(input_data: &str) -> Result<Self::Input> {
+        Ok(BathroomWatch {
+            bathroom: Bathroom::from_input(input_data)?,
+            detector: detector_by_name(DEFAULT_DETECTOR)?,
+        })
+    }
+
+    fn solve_part_1(watch: &Self::Input) -> Result<String> {
+        solve_part_1(&watch.bathroom)
+    }
+
+    fn solve_part_2(watch: &Self::Input) -> Result<String> {
+        solve_part_2(watch)
+    }
+
+    fn configure(watch: &mut Self::Input, options: &DayOptions) -> Result<()> {
+        if let Some(name) = &options.detector {
+            watch.detector = detector_by_name(name)?;
+        }
+        Ok(())
+    }
+
+    fn simulation(watch: &Self::Input, part: Part) -> Option<Box<dyn Simulation + '_>> {
+        match part {
+            Part::One => Some(Box::new(watch.bathroom.clone())),
+            Part::Two => Some(Box::new(TreeHunt {
+                bathroom: watch.bathroom.clone(),
+                detector: watch.detector.name(),
+                tree: find_tree(&watch.bathroom, watch.detector.as_ref()).ok(),
+            })),
+        }
+    }
+}
+
+#[cfg(test)]
+mod day_14_tests {
+    use crate::days::day_14::bathroom::Bathroom;
+    use crate::days::day_14::Day14;
+    use crate::{DayOptions, Part, Solution};
+
+    #[test]
+    fn should_pick_the_detector_from_the_options() {
+        let input = include_str!("../../../examples/day14/example.txt");
+        let mut watch = Day14::parse_input(input).unwrap();
+        assert_eq!("axis variance", watch.detector.name());
+
+        let options = DayOptions {
+            detector: Some("entropy".to_string()),
+        };
+        Day14::configure(&mut watch, &options).unwrap();
+        assert_eq!("entropy", watch.detector.name());
+        let render = Day14::simulation(&watch, Part::Two).unwrap().render();
+        assert!(render.contains("Tree candidate of the entropy detector: tick"));
+
+        let options = DayOptions {
+            detector: Some("astrology".to_string()),
+        };
+        let error = Day14::configure(&mut watch, &options).unwrap_err();
+        assert_eq!(
+            "Unknown detector: astrology (expected one of: axis variance, safety factor, \
+             longest run, entropy, largest component)",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn should_solve_part_1_example() {
+        let expected = 12;
+
+        let mut bathroom = Bathroom::new(11, 7);
+        bathroom
+            .load_robots_from_str(
+                "p=0,4 v=3,-3
+p=6,3 v=-1,-3
+p=10,3 v=-1,2
+p=2,0 v=2,-1
+p=0,0 v=1,3
+p=3,0 v=-2,-2
+p=7,6 v=-1,-3
+p=3,0 v=-1,-2
+p=9,3 v=2,3
+p=7,3 v=-1,2
+p=2,4 v=2,-3
+p=9,5 v=-3,-3",
+            )
+            .unwrap();
+
+        for _ in 0..100 {
+            bathroom.tick();
+        }
+
+        assert_eq!(expected, bathroom.get_safety_factor());
+    }
+}
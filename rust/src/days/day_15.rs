@@ -1,25 +1,84 @@
-use crate::warehouse::Warehouse;
-use crate::widewarehouse::WideWarehouse;
+use crate::days::day_15::warehouse::Warehouse;
+use crate::days::day_15::widewarehouse::WideWarehouse;
+use crate::grid::Grid;
+use crate::image::Palette;
+use crate::parse::{blocks, parse_grid, ParseError};
+use crate::position::Position;
+use crate::simulation::Simulation;
+use crate::{Part, Solution};
 use anyhow::*;
-use aoc2024::*;
+use std::collections::VecDeque;
 use std::str::FromStr;
 
 const DAY: &str = "15";
-const SOLUTION_PART_1: &str = "1463512";
-const SOLUTION_PART_2: &str = "1486520";
+
+// Map, moves and robot position, shared by both warehouse widths
+fn parse_warehouse(s: &str) -> Result<(Grid<char>, VecDeque<char>, Position), ParseError> {
+    let blocks = blocks(DAY, s);
+    let Some((map_lines, move_blocks)) = blocks.split_first() else {
+        return Err(ParseError::new(1, 1, "", "empty warehouse").with_day(DAY));
+    };
+
+    let tiles = parse_grid(map_lines, |char| match char {
+        '.' | '#' | 'O' | '@' => Ok(char),
+        _ => bail!("expected '.', '#', 'O' or '@'"),
+    })?;
+
+    let robot_position = match tiles.find_all(|tile| *tile == '@')[..] {
+        [robot_position] => robot_position,
+        [] => {
+            let last_line = map_lines[map_lines.len() - 1];
+            return Err(last_line.error_at_end("no robot '@' in the warehouse"));
+        }
+        [_, second, ..] => {
+            let line = map_lines[second.row as usize];
+            let row = line.text.trim();
+            let offset = row
+                .char_indices()
+                .nth(second.col as usize)
+                .map_or(0, |(offset, _)| offset);
+            return Err(line.error(&row[offset..offset + 1], "only one robot is expected"));
+        }
+    };
+
+    let mut moves = VecDeque::new();
+    for line in move_blocks.iter().flatten() {
+        let text = line.text.trim();
+        for (offset, char) in text.char_indices() {
+            match char {
+                '^' | '>' | 'v' | '<' => moves.push_back(char),
+                _ => {
+                    return Err(line.error(
+                        &text[offset..offset + char.len_utf8()],
+                        "expected a move ('^', '>', 'v' or '<')",
+                    ))
+                }
+            }
+        }
+    }
+
+    Result::Ok((tiles, moves, robot_position))
+}
 
 mod warehouse {
-    use crate::warehouse::MoveError::{BlockedBywall, ImpossibleToMoveCrate, NoMoreMoves};
+    use crate::days::day_15::parse_warehouse;
+    use crate::days::day_15::warehouse::MoveError::{
+        BlockedBywall, ImpossibleToMoveCrate, NoMoreMoves,
+    };
+    use crate::grid::Grid;
+    use crate::image::Palette;
+    use crate::parse::ParseError;
+    use crate::position::Position;
+    use crate::simulation::Simulation;
     use std::collections::VecDeque;
     use std::fmt::Display;
     use std::str::FromStr;
 
+    #[derive(Clone)]
     pub struct Warehouse {
-        height: usize,
-        width: usize,
-        tiles: Vec<char>,
+        tiles: Grid<char>,
         moves: VecDeque<char>,
-        robot_position: (usize, usize),
+        robot_position: Position,
     }
 
     enum MoveError {
@@ -28,41 +87,15 @@ mod warehouse {
         ImpossibleToMoveCrate,
     }
 
-    fn compute_new_position(position: &(usize, usize), increment: &(i32, i32)) -> (usize, usize) {
-        let new_row = (position.0 as i32 + increment.0) as usize;
-        let new_col = (position.1 as i32 + increment.1) as usize;
-        (new_row, new_col)
-    }
-
     impl Warehouse {
-        pub fn new() -> Warehouse {
-            Warehouse {
-                height: 0,
-                width: 0,
-                tiles: Vec::new(),
-                moves: VecDeque::new(),
-                robot_position: (0, 0),
-            }
-        }
-
-        fn position_to_index(&self, position: &(usize, usize)) -> usize {
-            position.0 * self.width + position.1
-        }
-
-        fn get_tile_from_position(&self, position: &(usize, usize)) -> char {
-            let index = self.position_to_index(position);
-
-            self.tiles[index]
-        }
-
         fn push_crate(
             &mut self,
-            position: &(usize, usize),
-            increment: &(i32, i32),
+            position: &Position,
+            increment: &Position,
         ) -> Result<(), MoveError> {
-            let next_position = &compute_new_position(position, &increment);
+            let next_position = &(*position + *increment);
 
-            match self.get_tile_from_position(next_position) {
+            match self.tiles[*next_position] {
                 '.' => self.unsafe_move(position, next_position),
                 'O' => match self.push_crate(next_position, increment) {
                     Ok(_) => self.unsafe_move(position, next_position),
@@ -75,24 +108,19 @@ mod warehouse {
 
         fn unsafe_move(
             &mut self,
-            old_position: &(usize, usize),
-            new_position: &(usize, usize),
+            old_position: &Position,
+            new_position: &Position,
         ) -> Result<(), MoveError> {
-            let char = self.get_tile_from_position(old_position);
-
-            let new_index = self.position_to_index(new_position);
-            self.tiles[new_index] = char;
-
-            let old_index = self.position_to_index(old_position);
-            self.tiles[old_index] = '.';
+            self.tiles[*new_position] = self.tiles[*old_position];
+            self.tiles[*old_position] = '.';
 
             Ok(())
         }
 
         fn unsafe_robot_move(
             &mut self,
-            old_position: &(usize, usize),
-            new_position: &(usize, usize),
+            old_position: &Position,
+            new_position: &Position,
         ) -> Result<(), MoveError> {
             self.robot_position = *new_position;
             self.unsafe_move(old_position, new_position)
@@ -100,18 +128,18 @@ mod warehouse {
 
         fn move_robot_once(&mut self) -> Result<(), MoveError> {
             let increment = match self.moves.pop_front() {
-                Some('^') => (-1, 0),
-                Some('>') => (0, 1),
-                Some('v') => (1, 0),
-                Some('<') => (0, -1),
+                Some('^') => Position::new(-1, 0),
+                Some('>') => Position::new(0, 1),
+                Some('v') => Position::new(1, 0),
+                Some('<') => Position::new(0, -1),
                 Some(_) => unreachable!("Invalid move"),
                 None => return Err(NoMoreMoves),
             };
 
             let current_position = &self.robot_position.clone();
-            let next_position = &compute_new_position(current_position, &increment);
+            let next_position = &(*current_position + increment);
 
-            match self.get_tile_from_position(next_position) {
+            match self.tiles[*next_position] {
                 '#' => Err(BlockedBywall),
                 '.' => self.unsafe_robot_move(current_position, next_position),
                 'O' => match self.push_crate(next_position, &increment) {
@@ -136,74 +164,56 @@ mod warehouse {
         }
 
         pub fn calculate_gps_coordinates(&mut self) -> Vec<usize> {
-            let mut gps_coords: Vec<usize> = Vec::new();
-
-            for row in 0..self.height {
-                for col in 0..self.width {
-                    let char = self.get_tile_from_position(&(row, col));
-                    if char == 'O' {
-                        gps_coords.push(100 * row + col);
-                    }
-                }
-            }
-            gps_coords
+            self.tiles
+                .find_all(|tile| *tile == 'O')
+                .into_iter()
+                .map(|position| (100 * position.row + position.col) as usize)
+                .collect()
         }
     }
 
     impl Display for Warehouse {
         fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-            let mut output = String::new();
-
-            for row in 0..self.height {
-                for col in 0..self.width {
-                    output.push(self.tiles[row * self.width + col]);
-                }
-                output.push('\n');
-            }
-            write!(f, "{}", output)
+            writeln!(f, "{}", self.tiles)
         }
     }
 
-    impl FromStr for Warehouse {
-        type Err = anyhow::Error;
+    impl Simulation for Warehouse {
+        fn tick(&mut self) -> bool {
+            !matches!(self.move_robot_once(), Err(NoMoreMoves))
+        }
 
-        fn from_str(s: &str) -> Result<Self, Self::Err> {
-            let mut warehouse = Warehouse::new();
+        fn render(&self) -> String {
+            format!("{}\n{}", self, super::render_next_move(&self.moves))
+        }
 
-            let lines = s.lines();
+        fn tiles(&self) -> Grid<char> {
+            self.tiles.clone()
+        }
 
-            let mut in_map_section = true;
-            for (row, line) in lines.enumerate() {
-                if in_map_section {
-                    if line.trim().is_empty() {
-                        in_map_section = false;
-                        continue;
-                    }
-                    warehouse.height += 1;
+        fn palette(&self) -> Palette {
+            super::warehouse_palette()
+        }
+    }
 
-                    for (col, char) in line.trim().chars().enumerate() {
-                        if row == 0 {
-                            warehouse.width += 1;
-                        }
-                        warehouse.tiles.push(char);
-                        if char == '@' {
-                            warehouse.robot_position = (row, col);
-                        }
-                    }
-                } else {
-                    for char in line.trim().chars() {
-                        warehouse.moves.push_back(char);
-                    }
-                }
-            }
+    impl FromStr for Warehouse {
+        type Err = ParseError;
 
-            Ok(warehouse)
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            let (tiles, moves, robot_position) = parse_warehouse(s)?;
+
+            Ok(Warehouse {
+                tiles,
+                moves,
+                robot_position,
+            })
         }
     }
 
     #[cfg(test)]
     mod tests {
-        use crate::warehouse::Warehouse;
+        use crate::days::day_15::warehouse::Warehouse;
+        use crate::position::Position;
         use std::collections::VecDeque;
         use std::str::FromStr;
         const EXAMPLE_DATA: &str = "##########
@@ -243,7 +253,7 @@ v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^
         fn should_load_from_str() {
             let expected_height = 8;
             let expected_width = 8;
-            let expected_robot_position = (2, 2);
+            let expected_robot_position = Position::new(2, 2);
             let expected_map = "########
 #..O.O.#
 ##@.O..#
@@ -257,8 +267,8 @@ v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^
 
             let warehouse = Warehouse::from_str(SIMPLE_EXAMPLE).unwrap();
 
-            assert_eq!(expected_height, warehouse.height);
-            assert_eq!(expected_width, warehouse.width);
+            assert_eq!(expected_height, warehouse.tiles.height());
+            assert_eq!(expected_width, warehouse.tiles.width());
             assert_eq!(expected_map, format!("{}", warehouse));
             assert_eq!(expected_robot_position, warehouse.robot_position);
             assert_eq!(expected_moves, warehouse.moves);
@@ -318,18 +328,23 @@ v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^
 }
 
 mod widewarehouse {
-    use crate::widewarehouse::MoveDirection::{Down, Left, Right, Up};
-    use crate::widewarehouse::MoveError::{BlockedBywall, ImpossibleToMoveCrate};
+    use crate::days::day_15::parse_warehouse;
+    use crate::days::day_15::widewarehouse::MoveDirection::{Down, Left, Right, Up};
+    use crate::days::day_15::widewarehouse::MoveError::{BlockedBywall, ImpossibleToMoveCrate};
+    use crate::grid::Grid;
+    use crate::image::Palette;
+    use crate::parse::ParseError;
+    use crate::position::Position;
+    use crate::simulation::Simulation;
     use std::collections::VecDeque;
     use std::fmt::Display;
     use std::str::FromStr;
 
+    #[derive(Clone)]
     pub struct WideWarehouse {
-        height: usize,
-        width: usize,
-        tiles: Vec<char>,
+        tiles: Grid<char>,
         moves: VecDeque<char>,
-        robot_position: (usize, usize),
+        robot_position: Position,
     }
 
     #[derive(Debug)]
@@ -347,95 +362,61 @@ mod widewarehouse {
     }
 
     impl WideWarehouse {
-        pub fn new() -> WideWarehouse {
-            WideWarehouse {
-                height: 0,
-                width: 0,
-                tiles: Vec::new(),
-                moves: VecDeque::new(),
-                robot_position: (0, 0),
-            }
-        }
-
-        fn position_to_index(&self, position: &(usize, usize)) -> usize {
-            position.0 * self.width + position.1
-        }
-
-        fn get_tile_from_position(&self, position: &(usize, usize)) -> char {
-            let index = self.position_to_index(position);
-
-            self.tiles[index]
-        }
-
-        fn unsafe_move_robot(&mut self, new_position: (usize, usize)) -> Result<(), MoveError> {
-            let old_position = &self.robot_position;
-            let old_index = self.position_to_index(old_position);
-            self.tiles[old_index] = '.';
-
-            let new_index = self.position_to_index(&new_position);
-            self.tiles[new_index] = '@';
+        fn unsafe_move_robot(&mut self, new_position: Position) -> Result<(), MoveError> {
+            self.tiles[self.robot_position] = '.';
+            self.tiles[new_position] = '@';
             self.robot_position = new_position;
 
             Ok(())
         }
 
-        fn unsafe_move_crate(&mut self, old_position: &(usize, usize), direction: MoveDirection) {
-            let old_positions = [
-                (old_position.0, old_position.1),
-                (old_position.0, old_position.1 + 1),
-            ];
+        fn unsafe_move_crate(&mut self, old_position: &Position, direction: MoveDirection) {
+            let old_positions = [*old_position, *old_position + Position::new(0, 1)];
 
             let new_positions = match direction {
                 Up => vec![
-                    (old_position.0 - 1, old_position.1),
-                    (old_position.0 - 1, old_position.1 + 1),
+                    *old_position + Position::new(-1, 0),
+                    *old_position + Position::new(-1, 1),
                 ],
                 Down => vec![
-                    (old_position.0 + 1, old_position.1),
-                    (old_position.0 + 1, old_position.1 + 1),
-                ],
-                Left => vec![
-                    (old_position.0, old_position.1 - 1),
-                    (old_position.0, old_position.1),
+                    *old_position + Position::new(1, 0),
+                    *old_position + Position::new(1, 1),
                 ],
+                Left => vec![*old_position + Position::new(0, -1), *old_position],
                 Right => vec![
-                    (old_position.0, old_position.1 + 1),
-                    (old_position.0, old_position.1 + 2),
+                    *old_position + Position::new(0, 1),
+                    *old_position + Position::new(0, 2),
                 ],
             };
 
             for (i, old_position) in old_positions.iter().enumerate() {
-                let index = self.position_to_index(old_position);
                 let mut expected_old_char = '[';
                 if i == 1 {
                     expected_old_char = ']';
                 }
 
-                let actual_char = self.get_tile_from_position(old_position);
-
-                if expected_old_char == actual_char {
-                    self.tiles[index] = '.';
+                if expected_old_char == self.tiles[*old_position] {
+                    self.tiles[*old_position] = '.';
                 }
             }
 
             for (i, new_position) in new_positions.into_iter().enumerate() {
-                let index = self.position_to_index(&new_position);
                 if i == 0 {
-                    self.tiles[index] = '[';
+                    self.tiles[new_position] = '[';
                 } else {
-                    self.tiles[index] = ']';
+                    self.tiles[new_position] = ']';
                 }
             }
         }
 
         fn get_crate_neighbours_on_move(
             &self,
-            position: &(usize, usize),
+            position: &Position,
             direction: MoveDirection,
-        ) -> Vec<(usize, usize)> {
-            let actual_position = match self.get_tile_from_position(position) {
+        ) -> Vec<Position> {
+            let actual_position = match self.tiles[*position] {
                 '[' => position,
-                ']' => &(position.0, position.1 - 1),
+                ']' => &(*position - Position::new(0, 1)),
                 _ => {
                     return Vec::new();
                 }
@@ -443,28 +424,28 @@ mod widewarehouse {
 
             match direction {
                 Up => vec![
-                    (actual_position.0 - 1, actual_position.1),
-                    (actual_position.0 - 1, actual_position.1 + 1),
+                    *actual_position + Position::new(-1, 0),
+                    *actual_position + Position::new(-1, 1),
                 ],
                 Down => vec![
-                    (actual_position.0 + 1, actual_position.1),
-                    (actual_position.0 + 1, actual_position.1 + 1),
+                    *actual_position + Position::new(1, 0),
+                    *actual_position + Position::new(1, 1),
                 ],
-                Left => vec![(actual_position.0, actual_position.1 - 1)],
-                Right => vec![(actual_position.0, actual_position.1 + 2)],
+                Left => vec![*actual_position + Position::new(0, -1)],
+                Right => vec![*actual_position + Position::new(0, 2)],
             }
         }
 
         fn get_crates_to_be_moved(
             &self,
-            starting_position: &(usize, usize),
+            starting_position: &Position,
             direction: MoveDirection,
-        ) -> Vec<(usize, usize)> {
+        ) -> Vec<Position> {
             let mut crates = Vec::new();
 
-            let actual_position = match self.get_tile_from_position(starting_position) {
+            let actual_position = match self.tiles[*starting_position] {
                 '[' => starting_position,
-                ']' => &(starting_position.0, starting_position.1 - 1),
+                ']' => &(*starting_position - Position::new(0, 1)),
                 _ => {
                     return Vec::new();
                 }
@@ -472,7 +453,7 @@ mod widewarehouse {
 
             crates.push(*actual_position);
 
-            let neighbours = self.get_crate_neighbours_on_move(&actual_position, direction);
+            let neighbours = self.get_crate_neighbours_on_move(actual_position, direction);
 
             for neighbour_position in neighbours {
                 crates.append(&mut self.get_crates_to_be_moved(&neighbour_position, direction));
@@ -481,16 +462,11 @@ mod widewarehouse {
             crates
         }
 
-        fn can_crates_be_moved(
-            &self,
-            crates: &Vec<(usize, usize)>,
-            direction: MoveDirection,
-        ) -> bool {
+        fn can_crates_be_moved(&self, crates: &Vec<Position>, direction: MoveDirection) -> bool {
             for position in crates {
                 let neighbours = self.get_crate_neighbours_on_move(position, direction);
                 for neighbour_position in neighbours {
-                    let tile = self.get_tile_from_position(&neighbour_position);
-                    if tile == '#' {
+                    if self.tiles[neighbour_position] == '#' {
                         return false;
                     }
                 }
@@ -501,13 +477,13 @@ mod widewarehouse {
         fn move_robot_once(&mut self, direction: MoveDirection) -> Result<(), MoveError> {
             let current_position = self.robot_position;
             let next_position = match direction {
-                Up => (current_position.0 - 1, current_position.1),
-                Down => (current_position.0 + 1, current_position.1),
-                Left => (current_position.0, current_position.1 - 1),
-                Right => (current_position.0, current_position.1 + 1),
+                Up => current_position + Position::new(-1, 0),
+                Down => current_position + Position::new(1, 0),
+                Left => current_position + Position::new(0, -1),
+                Right => current_position + Position::new(0, 1),
             };
 
-            let neighbour = self.get_tile_from_position(&next_position);
+            let neighbour = self.tiles[next_position];
 
             match neighbour {
                 '.' => self.unsafe_move_robot(next_position),
@@ -528,104 +504,86 @@ mod widewarehouse {
             }
         }
 
-        pub fn move_robot_until_done(&mut self) {
-            loop {
-                let robot_move_opt = self.moves.pop_front();
+        // Returns false when there are no more moves
+        fn move_next_robot(&mut self) -> bool {
+            let Some(robot_move) = self.moves.pop_front() else {
+                return false;
+            };
 
-                if robot_move_opt.is_none() {
-                    break;
-                }
+            // Blocked moves are simply skipped
+            let _ = match robot_move {
+                '^' => self.move_robot_once(Up),
+                '>' => self.move_robot_once(Right),
+                'v' => self.move_robot_once(Down),
+                '<' => self.move_robot_once(Left),
+                _ => unreachable!("Unknown move: {}", robot_move),
+            };
+            true
+        }
 
-                let robot_move = robot_move_opt.unwrap();
-
-                if (match robot_move {
-                    '^' => self.move_robot_once(Up),
-                    '>' => self.move_robot_once(Right),
-                    'v' => self.move_robot_once(Down),
-                    '<' => self.move_robot_once(Left),
-                    _ => unreachable!("Unknown move: {}", robot_move),
-                })
-                .is_err()
-                {}
-            }
+        pub fn move_robot_until_done(&mut self) {
+            while self.move_next_robot() {}
         }
 
         pub fn calculate_gps_coordinates(&mut self) -> Vec<usize> {
-            let mut gps_coords: Vec<usize> = Vec::new();
-
-            for row in 0..self.height {
-                for col in 0..self.width {
-                    let char = self.get_tile_from_position(&(row, col));
-                    if char == '[' {
-                        gps_coords.push(100 * row + col);
-                    }
-                }
-            }
-            gps_coords
+            self.tiles
+                .find_all(|tile| *tile == '[')
+                .into_iter()
+                .map(|position| (100 * position.row + position.col) as usize)
+                .collect()
         }
     }
 
     impl FromStr for WideWarehouse {
-        type Err = anyhow::Error;
+        type Err = ParseError;
 
         fn from_str(s: &str) -> Result<Self, Self::Err> {
-            let mut warehouse = WideWarehouse::new();
-
-            let lines = s.lines();
-
-            let mut in_map_section = true;
-            for (row, line) in lines.enumerate() {
-                if in_map_section {
-                    if line.trim().is_empty() {
-                        in_map_section = false;
-                        continue;
-                    }
-                    warehouse.height += 1;
-
-                    for (col, char) in line.trim().chars().enumerate() {
-                        if row == 0 {
-                            warehouse.width += 2;
-                        }
-
-                        match char {
-                            '.' => {
-                                warehouse.tiles.append(&mut vec!['.', '.']);
-                            }
-                            '#' => {
-                                warehouse.tiles.append(&mut vec!['#', '#']);
-                            }
-                            'O' => {
-                                warehouse.tiles.append(&mut vec!['[', ']']);
-                            }
-                            '@' => {
-                                warehouse.tiles.append(&mut vec!['@', '.']);
-                                warehouse.robot_position = (row, col * 2usize);
-                            }
-                            _ => unreachable!("unexpected char in row {}: {}", row, char),
-                        }
-                    }
-                } else {
-                    for char in line.trim().chars() {
-                        warehouse.moves.push_back(char);
-                    }
+            let (narrow_tiles, moves, narrow_robot_position) = parse_warehouse(s)?;
+
+            let mut wide_tiles =
+                Vec::with_capacity(narrow_tiles.width() * narrow_tiles.height() * 2);
+            for (_, char) in narrow_tiles.iter() {
+                match char {
+                    'O' => wide_tiles.extend(['[', ']']),
+                    '@' => wide_tiles.extend(['@', '.']),
+                    _ => wide_tiles.extend([*char, *char]),
                 }
             }
-
-            Ok(warehouse)
+            let tiles =
+                Grid::from_cells(narrow_tiles.width() * 2, narrow_tiles.height(), wide_tiles)
+                    .expect("every narrow tile is widened to two tiles");
+            let robot_position =
+                Position::new(narrow_robot_position.row, narrow_robot_position.col * 2);
+
+            Ok(WideWarehouse {
+                tiles,
+                moves,
+                robot_position,
+            })
         }
     }
 
     impl Display for WideWarehouse {
         fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-            let mut output = String::new();
+            writeln!(f, "{}", self.tiles)
+        }
+    }
 
-            for row in 0..self.height {
-                for col in 0..self.width {
-                    output.push(self.tiles[row * self.width + col]);
-                }
-                output.push('\n');
-            }
-            write!(f, "{}", output)
+    impl Simulation for WideWarehouse {
+        fn tick(&mut self) -> bool {
+            self.move_next_robot()
+        }
+
+        fn render(&self) -> String {
+            format!("{}\n{}", self, super::render_next_move(&self.moves))
+        }
+
+        fn tiles(&self) -> Grid<char> {
+            self.tiles.clone()
+        }
+
+        fn palette(&self) -> Palette {
+            super::warehouse_palette()
         }
     }
 
@@ -668,7 +626,7 @@ v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^
         fn should_load_from_str() {
             let expected_height = 7;
             let expected_width = 14;
-            let expected_robot_position = (3, 10);
+            let expected_robot_position = Position::new(3, 10);
             let expected_map = "##############
 ##......##..##
 ##..........##
@@ -681,8 +639,8 @@ v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^
 
             let warehouse = WideWarehouse::from_str(SIMPLE_EXAMPLE).unwrap();
 
-            assert_eq!(expected_height, warehouse.height);
-            assert_eq!(expected_width, warehouse.width);
+            assert_eq!(expected_height, warehouse.tiles.height());
+            assert_eq!(expected_width, warehouse.tiles.width());
             assert_eq!(expected_map, format!("{}", warehouse));
             assert_eq!(expected_robot_position, warehouse.robot_position);
             assert_eq!(expected_moves, warehouse.moves);
@@ -722,6 +680,21 @@ v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^
             assert_eq!(expected_output, format!("{}", warehouse));
         }
 
+        #[test]
+        fn should_simulate_one_move_per_tick() {
+            let mut simulated = WideWarehouse::from_str(EXAMPLE_DATA).unwrap();
+            let mut ticks = 0;
+            while simulated.tick() {
+                ticks += 1;
+            }
+
+            let mut warehouse = WideWarehouse::from_str(EXAMPLE_DATA).unwrap();
+            warehouse.move_robot_until_done();
+            assert_eq!(700, ticks);
+            assert_eq!(format!("{}", warehouse), format!("{}", simulated));
+            assert!(simulated.render().ends_with("No more moves"));
+        }
+
         #[test]
         fn should_calculate_gps_coordinates() {
             let expected_sum = 9021usize;
@@ -737,10 +710,23 @@ v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^
     }
 }
 
+fn warehouse_palette() -> Palette {
+    Palette::default()
+        .with_all("O[]", [180, 120, 60])
+        .with('@', [220, 40, 40])
+}
+
+fn render_next_move(moves: &VecDeque<char>) -> String {
+    match moves.front() {
+        Some(next_move) => format!("Next move: {} ({} left)", next_move, moves.len()),
+        None => "No more moves".to_string(),
+    }
+}
+
 //region Part 1
 
-fn solve_part_1(input_data: &str) -> Result<String> {
-    let mut warehouse = Warehouse::from_str(input_data)?;
+fn solve_part_1(warehouse: &Warehouse) -> Result<String> {
+    let mut warehouse = warehouse.clone();
     warehouse.move_robot_until_done();
 
     Ok(format!(
@@ -752,8 +738,8 @@ fn solve_part_1(input_data: &str) -> Result<String> {
 
 //region Part 2
 
-fn solve_part_2(input_data: &str) -> Result<String> {
-    let mut warehouse = WideWarehouse::from_str(input_data)?;
+fn solve_part_2(warehouse: &WideWarehouse) -> Result<String> {
+    let mut warehouse = warehouse.clone();
     warehouse.move_robot_until_done();
 
     Ok(format!(
@@ -763,27 +749,35 @@ fn solve_part_2(input_data: &str) -> Result<String> {
 }
 //endregion
 
-fn main() -> Result<()> {
-    start_day(DAY);
-
-    let (input_data, duration) = time_function!(get_input_data(DAY)?);
-    println!("Input data loaded in {}", pretty_duration(duration));
-
-    let (answer_part_1, part1_duration) = time_function!(solve_part_1(&input_data)?);
-    println!(
-        "Part 1: {} (solved in {})",
-        answer_part_1,
-        pretty_duration(part1_duration)
-    );
-    check_result(&answer_part_1, SOLUTION_PART_1);
-
-    let (answer_part_2, part2_duration) = time_function!(solve_part_2(&input_data)?);
-    println!(
-        "Part 2: {} (solved in {})",
-        answer_part_2,
-        pretty_duration(part2_duration)
-    );
-    check_result(&answer_part_2, SOLUTION_PART_2);
-
-    Ok(())
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: &'static str = DAY;
+
+    type Input = (Warehouse, WideWarehouse);
+
+    fn parse_input(input_data: &str) -> Result<Self::Input> {
+        Ok((
+            Warehouse::from_str(input_data)?,
+            WideWarehouse::from_str(input_data)?,
+        ))
+    }
+
+    fn solve_part_1((warehouse, _): &Self::Input) -> Result<String> {
+        solve_part_1(warehouse)
+    }
+
+    fn solve_part_2((_, wide_warehouse): &Self::Input) -> Result<String> {
+        solve_part_2(wide_warehouse)
+    }
+
+    fn simulation(
+        (warehouse, wide_warehouse): &Self::Input,
+        part: Part,
+    ) -> Option<Box<dyn Simulation + '_>> {
+        match part {
+            Part::One => Some(Box::new(warehouse.clone())),
+            Part::Two => Some(Box::new(wide_warehouse.clone())),
+        }
+    }
 }
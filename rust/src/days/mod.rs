@@ -0,0 +1,99 @@
+use crate::runner::RegisteredDay;
+
+mod day_01;
+mod day_02;
+mod day_03;
+mod day_04;
+mod day_05;
+mod day_06;
+mod day_07;
+mod day_08;
+mod day_09;
+mod day_10;
+mod day_11;
+mod day_12;
+mod day_13;
+mod day_14;
+mod day_15;
+
+// The day 6 patrol, to list the trail of a guard and the loop it gets stuck in
+pub use day_06::{Cycle, GuardPatrol, GuardPatrols, GuardStop};
+
+pub fn registry() -> Vec<RegisteredDay> {
+    vec![
+        RegisteredDay::of::<day_01::Day01>(),
+        RegisteredDay::of::<day_02::Day02>(),
+        RegisteredDay::of::<day_03::Day03>(),
+        RegisteredDay::of::<day_04::Day04>(),
+        RegisteredDay::of::<day_05::Day05>(),
+        RegisteredDay::of::<day_06::Day06>(),
+        RegisteredDay::of::<day_07::Day07>(),
+        RegisteredDay::of::<day_08::Day08>(),
+        RegisteredDay::of::<day_09::Day09>(),
+        RegisteredDay::of::<day_10::Day10>(),
+        RegisteredDay::of::<day_11::Day11>(),
+        RegisteredDay::of::<day_12::Day12>(),
+        RegisteredDay::of::<day_13::Day13>(),
+        RegisteredDay::of::<day_14::Day14>(),
+        RegisteredDay::of::<day_15::Day15>(),
+    ]
+}
+
+pub fn find_day(day: &str) -> Option<RegisteredDay> {
+    registry()
+        .into_iter()
+        .find(|registered| registered.day == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::position::Position;
+
+    crate::fixture_tests!(
+        day_01::Day01,
+        day_02::Day02,
+        day_03::Day03,
+        day_04::Day04,
+        day_05::Day05,
+        day_06::Day06,
+        day_07::Day07,
+        day_08::Day08,
+        day_09::Day09,
+        day_10::Day10,
+        day_11::Day11,
+        day_12::Day12,
+        day_13::Day13,
+        day_14::Day14,
+        day_15::Day15,
+    );
+
+    #[test]
+    fn should_have_fixture_tests_for_every_day() {
+        for registered in registry() {
+            assert!(
+                FIXTURE_DAYS.contains(&registered.day),
+                "day {} is missing from fixture_tests!",
+                registered.day
+            );
+        }
+    }
+
+    #[test]
+    fn should_explain_a_day_6_loop_through_the_public_api() {
+        let input = include_str!("../../../examples/day06/example.txt");
+        let mut patrol = GuardPatrols::from_input(input).unwrap().patrols()[0].clone();
+
+        patrol.add_obstruction(Position::new(7, 6));
+        patrol.run();
+
+        let cycle: Cycle = patrol.cycle().unwrap();
+        let trail: Vec<GuardStop> = patrol.trail();
+        assert!(trail.contains(&cycle.entry));
+        assert_eq!(4, cycle.turns);
+        assert_eq!(
+            Some('O'),
+            patrol.render_trail().get(Position::new(7, 6)).copied()
+        );
+    }
+}
@@ -0,0 +1,196 @@
+use crate::answers::{AnswerStore, ANSWERS_FILE_NAME};
+use crate::scaffold::day_examples_dir;
+use crate::{Part, Solution};
+use anyhow::{bail, Context, Result};
+use std::fs;
+use std::path::Path;
+
+// An example input of examples/dayXX/ with the answers listed in its answers.toml
+#[derive(Debug, PartialEq)]
+pub struct Fixture {
+    pub name: String,
+    pub input_data: String,
+    pub expected: Vec<(Part, String)>,
+}
+
+// Fixtures without any known answer (e.g. freshly scaffolded stubs) are skipped, but a missing
+// directory, answers file or fixture is an error so that a typo can't silently check nothing
+pub fn load_fixtures(examples_dir: &Path, day: &str) -> Result<Vec<Fixture>> {
+    let dir = day_examples_dir(examples_dir, day);
+    if !dir.is_dir() {
+        bail!("Missing fixtures directory {}", dir.display());
+    }
+    let answers_path = dir.join(ANSWERS_FILE_NAME);
+    if !answers_path.is_file() {
+        bail!("Missing fixture answers {}", answers_path.display());
+    }
+    let answers = AnswerStore::load(&answers_path)?;
+
+    let mut names = Vec::new();
+    for entry in fs::read_dir(&dir).with_context(|| format!("Could not read {}", dir.display()))? {
+        let path = entry?.path();
+        if path.extension().is_some_and(|extension| extension == "txt") {
+            if let Some(name) = path.file_name() {
+                names.push(name.to_string_lossy().into_owned());
+            }
+        }
+    }
+    names.sort();
+    if names.is_empty() {
+        bail!("No fixture in {}", dir.display());
+    }
+
+    let mut fixtures = Vec::new();
+    for name in names {
+        let expected: Vec<(Part, String)> = Part::ALL
+            .into_iter()
+            .filter_map(|part| Some((part, answers.get(day, &name, part)?.to_string())))
+            .collect();
+        if expected.is_empty() {
+            continue;
+        }
+        let path = dir.join(&name);
+        let input_data = fs::read_to_string(&path)
+            .with_context(|| format!("Could not read fixture {}", path.display()))?;
+        fixtures.push(Fixture {
+            name,
+            input_data,
+            expected,
+        });
+    }
+
+    if fixtures.is_empty() && !answers.is_empty() {
+        bail!(
+            "None of the answers of {} is for a day {} fixture",
+            answers_path.display(),
+            day
+        );
+    }
+    Ok(fixtures)
+}
+
+// Solves every fixture of the day, returns the number of checked answers
+pub fn check_fixtures<S: Solution>(examples_dir: &Path) -> Result<usize> {
+    let mut checked = 0;
+    let mut failures = Vec::new();
+    for fixture in load_fixtures(examples_dir, S::DAY)? {
+        let input = S::parse_input(&fixture.input_data)
+            .with_context(|| format!("Could not parse {}", fixture.name))?;
+        for (part, expected) in &fixture.expected {
+            checked += 1;
+            match S::solve(*part, &input) {
+                Ok(actual) if actual == *expected => {}
+                Ok(actual) => failures.push(format!(
+                    "{} part {}: expected {}, actual {}",
+                    fixture.name, part, expected, actual
+                )),
+                Err(error) => failures.push(format!(
+                    "{} part {}: expected {}, failed with {:#}",
+                    fixture.name, part, expected, error
+                )),
+            }
+        }
+    }
+
+    if !failures.is_empty() {
+        bail!("Day {} fixtures failed:\n{}", S::DAY, failures.join("\n"));
+    }
+    Ok(checked)
+}
+
+// Generates a test module per day checking its fixtures, plus the list of the covered days
+#[macro_export]
+macro_rules! fixture_tests {
+    ($($module:ident :: $day:ident),* $(,)?) => {
+        pub const FIXTURE_DAYS: &[&str] = &[$(<$crate::days::$module::$day as $crate::Solution>::DAY),*];
+
+        $(
+            mod $module {
+                #[test]
+                fn should_solve_fixtures() {
+                    let checked = $crate::fixtures::check_fixtures::<$crate::days::$module::$day>(
+                        &$crate::scaffold::examples_dir(),
+                    )
+                    .unwrap();
+                    assert!(checked > 0, "No answer to check in the fixtures of {}", stringify!($module));
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scaffold::example_answers_stub;
+
+    struct LineCount;
+
+    impl Solution for LineCount {
+        const DAY: &'static str = "42";
+        type Input = usize;
+
+        fn parse_input(input_data: &str) -> Result<usize> {
+            Ok(input_data.lines().count())
+        }
+
+        fn solve_part_1(input: &usize) -> Result<String> {
+            Ok(input.to_string())
+        }
+
+        fn solve_part_2(input: &usize) -> Result<String> {
+            Ok((input * 2).to_string())
+        }
+    }
+
+    #[test]
+    fn should_check_fixtures() {
+        let examples_dir =
+            std::env::temp_dir().join(format!("aoc-fixtures-{}", std::process::id()));
+        let dir = day_examples_dir(&examples_dir, "42");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("example.txt"), "a\nb\n").unwrap();
+        fs::write(dir.join("stub.txt"), "").unwrap();
+        fs::write(dir.join("notes.md"), "not a fixture").unwrap();
+        fs::write(
+            dir.join(ANSWERS_FILE_NAME),
+            "[\"42\".\"example.txt\"]\npart_1 = \"2\"\npart_2 = \"4\"\n\n[\"42\".\"stub.txt\"]\n# part_1 = \"\"\n",
+        )
+        .unwrap();
+
+        let fixtures = load_fixtures(&examples_dir, "42").unwrap();
+        assert_eq!(1, fixtures.len());
+        assert_eq!("example.txt", fixtures[0].name);
+        assert_eq!(2, check_fixtures::<LineCount>(&examples_dir).unwrap());
+
+        fs::write(
+            dir.join(ANSWERS_FILE_NAME),
+            "[\"42\".\"example.txt\"]\npart_2 = \"5\"\n",
+        )
+        .unwrap();
+        let error = check_fixtures::<LineCount>(&examples_dir).unwrap_err();
+        assert!(error
+            .to_string()
+            .contains("example.txt part 2: expected 5, actual 4"));
+
+        // A scaffolded stub without answers checks nothing, which fails the generated fixture tests
+        fs::write(dir.join(ANSWERS_FILE_NAME), example_answers_stub("42")).unwrap();
+        assert_eq!(0, check_fixtures::<LineCount>(&examples_dir).unwrap());
+
+        // But answers that match no fixture, or nothing to check at all, are errors
+        fs::write(
+            dir.join(ANSWERS_FILE_NAME),
+            "[\"24\".\"example.txt\"]\npart_1 = \"2\"\n",
+        )
+        .unwrap();
+        assert!(check_fixtures::<LineCount>(&examples_dir).is_err());
+        fs::remove_file(dir.join(ANSWERS_FILE_NAME)).unwrap();
+        assert!(check_fixtures::<LineCount>(&examples_dir).is_err());
+        let missing_dir =
+            std::env::temp_dir().join(format!("aoc-no-fixtures-{}", std::process::id()));
+        let error = check_fixtures::<LineCount>(&missing_dir).unwrap_err();
+        assert!(error.to_string().starts_with("Missing fixtures directory"));
+
+        fs::remove_dir_all(&examples_dir).unwrap();
+    }
+}
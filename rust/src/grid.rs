@@ -0,0 +1,297 @@
+use crate::parse::{input_lines, parse_grid, InputLine, ParseError};
+use crate::position::Position;
+use anyhow::{bail, Result};
+use std::fmt::Display;
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+
+// Row-major grid of cells, addressed by positions
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn from_cells(width: usize, height: usize, cells: Vec<T>) -> Result<Self> {
+        if cells.len() != width * height {
+            bail!(
+                "A {}x{} grid needs {} cells (got {})",
+                width,
+                height,
+                width * height,
+                cells.len()
+            );
+        }
+        Ok(Self {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    // Every trimmed line is a row and every char is mapped to a cell, trailing empty lines are ignored
+    pub fn parse(s: &str, mapping: impl FnMut(char) -> Result<T>) -> Result<Self, ParseError> {
+        let lines: Vec<InputLine> = input_lines("", s.trim_end()).collect();
+        parse_grid(&lines, mapping)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, position: Position) -> bool {
+        (0..self.height as isize).contains(&position.row)
+            && (0..self.width as isize).contains(&position.col)
+    }
+
+    pub fn index_of(&self, position: Position) -> Option<usize> {
+        self.contains(position)
+            .then(|| position.row as usize * self.width + position.col as usize)
+    }
+
+    pub fn position_of(&self, index: usize) -> Position {
+        Position::new((index / self.width) as isize, (index % self.width) as isize)
+    }
+
+    pub fn get(&self, position: Position) -> Option<&T> {
+        self.index_of(position).map(|index| &self.cells[index])
+    }
+
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        self.index_of(position).map(|index| &mut self.cells[index])
+    }
+
+    // Neighbours inside the grid, in the order of Direction::ALL
+    pub fn neighbours_4(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        position
+            .neighbours_4()
+            .into_iter()
+            .filter(|neighbour| self.contains(*neighbour))
+    }
+
+    // Neighbours inside the grid, clockwise from north
+    pub fn neighbours_8(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        position
+            .neighbours_8()
+            .into_iter()
+            .filter(|neighbour| self.contains(*neighbour))
+    }
+
+    pub fn wrapping_neighbours_4(&self, position: Position) -> [Position; 4] {
+        position.wrapping_neighbours_4(self.width, self.height)
+    }
+
+    pub fn wrapping_neighbours_8(&self, position: Position) -> [Position; 8] {
+        position.wrapping_neighbours_8(self.width, self.height)
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Position> + '_ {
+        (0..self.cells.len()).map(|index| self.position_of(index))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(index, cell)| (self.position_of(index), cell))
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // chunks panics on a zero chunk size, which only happens for an empty grid
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn row(&self, row: usize) -> Option<&[T]> {
+        (row < self.height).then(|| &self.cells[row * self.width..(row + 1) * self.width])
+    }
+
+    pub fn col(&self, col: usize) -> impl Iterator<Item = &T> {
+        let height = if col < self.width { self.height } else { 0 };
+        (0..height).map(move |row| &self.cells[row * self.width + col])
+    }
+
+    pub fn find(&self, predicate: impl Fn(&T) -> bool) -> Option<Position> {
+        self.cells
+            .iter()
+            .position(predicate)
+            .map(|index| self.position_of(index))
+    }
+
+    pub fn find_all(&self, predicate: impl Fn(&T) -> bool) -> Vec<Position> {
+        self.iter()
+            .filter(|(_, cell)| predicate(cell))
+            .map(|(position, _)| position)
+            .collect()
+    }
+
+    pub fn map<U>(&self, mapping: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(mapping).collect(),
+        }
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &T {
+        match self.index_of(position) {
+            Some(index) => &self.cells[index],
+            None => panic!(
+                "position {} is out of a {}x{} grid",
+                position, self.width, self.height
+            ),
+        }
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut T {
+        match self.index_of(position) {
+            Some(index) => &mut self.cells[index],
+            None => panic!(
+                "position {} is out of a {}x{} grid",
+                position, self.width, self.height
+            ),
+        }
+    }
+}
+
+impl FromStr for Grid<char> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Grid::parse(s, Ok)
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (row, cells) in self.rows().enumerate() {
+            if row > 0 {
+                writeln!(f)?;
+            }
+            for cell in cells {
+                write!(f, "{}", cell)?;
+            }
+        }
+        std::fmt::Result::Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Context;
+
+    const EXAMPLE: &str = "#..
+.#.
+..@
+";
+
+    #[test]
+    fn should_parse_from_str() {
+        let grid: Grid<char> = EXAMPLE.parse().unwrap();
+
+        assert_eq!(3, grid.width());
+        assert_eq!(3, grid.height());
+        assert_eq!('@', grid[Position::new(2, 2)]);
+        assert_eq!(Some(&'#'), grid.get(Position::new(1, 1)));
+        assert_eq!(None, grid.get(Position::new(-1, 0)));
+        assert_eq!(None, grid.get(Position::new(0, 3)));
+        assert_eq!(EXAMPLE.trim_end(), grid.to_string());
+    }
+
+    #[test]
+    fn should_parse_with_mapping() {
+        let grid = Grid::parse("12\n34", |ch| ch.to_digit(10).context("not a digit")).unwrap();
+
+        assert_eq!(vec![1, 2], grid.row(0).unwrap());
+        assert_eq!(vec![&2, &4], grid.col(1).collect::<Vec<_>>());
+        assert_eq!(None, grid.row(2));
+        let error = Grid::parse("12\n1a", |ch| ch.to_digit(10).context("not a digit")).unwrap_err();
+        assert_eq!(
+            "line 2, column 2: not a digit (got \"a\")",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn should_reject_ragged_rows() {
+        let error = "..\n...".parse::<Grid<char>>().unwrap_err();
+
+        assert_eq!(2, error.line);
+        assert_eq!(3, error.column);
+        assert_eq!("expected 2 columns (got 3)", error.message);
+    }
+
+    #[test]
+    fn should_find_cells() {
+        let mut grid: Grid<char> = EXAMPLE.parse().unwrap();
+
+        assert_eq!(Some(Position::new(2, 2)), grid.find(|ch| *ch == '@'));
+        assert_eq!(
+            vec![Position::new(0, 0), Position::new(1, 1)],
+            grid.find_all(|ch| *ch == '#')
+        );
+
+        grid[Position::new(2, 2)] = '.';
+        assert_eq!(None, grid.find(|ch| *ch == '@'));
+    }
+
+    #[test]
+    fn should_list_neighbours_inside_the_grid() {
+        let grid: Grid<char> = EXAMPLE.parse().unwrap();
+
+        assert_eq!(
+            vec![Position::new(0, 1), Position::new(1, 0)],
+            grid.neighbours_4(Position::new(0, 0)).collect::<Vec<_>>()
+        );
+        assert_eq!(8, grid.neighbours_8(Position::new(1, 1)).count());
+        assert_eq!(3, grid.neighbours_8(Position::new(2, 2)).count());
+        assert_eq!(
+            [
+                Position::new(1, 2),
+                Position::new(2, 0),
+                Position::new(0, 2),
+                Position::new(2, 1)
+            ],
+            grid.wrapping_neighbours_4(Position::new(2, 2))
+        );
+    }
+
+    #[test]
+    fn should_iterate_in_row_major_order() {
+        let grid = Grid::from_cells(2, 2, vec![0, 1, 2, 3]).unwrap();
+
+        assert_eq!(
+            vec![
+                Position::new(0, 0),
+                Position::new(0, 1),
+                Position::new(1, 0),
+                Position::new(1, 1)
+            ],
+            grid.positions().collect::<Vec<_>>()
+        );
+        assert_eq!(vec![0, 2, 4, 6], grid.map(|cell| cell * 2).cells);
+        assert!(Grid::from_cells(2, 2, vec![0]).is_err());
+    }
+}
@@ -0,0 +1,316 @@
+use crate::bench::Stats;
+use crate::input::find_data_dir;
+use crate::Part;
+use anyhow::{anyhow, bail, Context, Result};
+use std::collections::BTreeMap;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::str::FromStr;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+pub const HISTORY_FILE_NAME: &str = "bench-history.csv";
+// The input name is the last column so that it may contain commas
+pub const HISTORY_HEADER: &str =
+    "timestamp,commit,day,part,runs,min_ns,median_ns,mean_ns,p95_ns,input";
+const HISTORY_COLUMNS: usize = 10;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct HistoryEntry {
+    pub timestamp: u64,
+    pub commit: Option<String>,
+    pub day: String,
+    pub part: Part,
+    pub input_name: String,
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub p95: Duration,
+}
+
+impl HistoryEntry {
+    pub fn new(
+        timestamp: u64,
+        commit: Option<String>,
+        day: &str,
+        part: Part,
+        input_name: &str,
+        stats: &Stats,
+    ) -> Self {
+        Self {
+            timestamp,
+            commit,
+            day: day.to_string(),
+            part,
+            input_name: input_name.to_string(),
+            runs: stats.runs,
+            min: stats.min,
+            median: stats.median,
+            mean: stats.mean,
+            p95: stats.p95,
+        }
+    }
+
+    fn to_csv(&self) -> String {
+        format!(
+            "{},{},{},{},{},{},{},{},{},{}",
+            self.timestamp,
+            self.commit.as_deref().unwrap_or(""),
+            self.day,
+            self.part,
+            self.runs,
+            self.min.as_nanos(),
+            self.median.as_nanos(),
+            self.mean.as_nanos(),
+            self.p95.as_nanos(),
+            self.input_name
+        )
+    }
+}
+
+fn parse_nanos(value: &str) -> Result<Duration> {
+    Ok(Duration::from_nanos(value.parse()?))
+}
+
+impl FromStr for HistoryEntry {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let columns: Vec<&str> = s.splitn(HISTORY_COLUMNS, ',').collect();
+        let [timestamp, commit, day, part, runs, min, median, mean, p95, input_name] = columns[..]
+        else {
+            bail!("expected {} columns", HISTORY_COLUMNS);
+        };
+
+        let part = match part {
+            "1" => Part::One,
+            "2" => Part::Two,
+            _ => bail!("invalid part {}", part),
+        };
+
+        Ok(Self {
+            timestamp: timestamp.parse()?,
+            commit: (!commit.is_empty()).then(|| commit.to_string()),
+            day: day.to_string(),
+            part,
+            input_name: input_name.to_string(),
+            runs: runs.parse()?,
+            min: parse_nanos(min)?,
+            median: parse_nanos(median)?,
+            mean: parse_nanos(mean)?,
+            p95: parse_nanos(p95)?,
+        })
+    }
+}
+
+pub fn default_history_path() -> Result<PathBuf> {
+    Ok(find_data_dir()?.join(HISTORY_FILE_NAME))
+}
+
+pub fn current_timestamp() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or_default()
+}
+
+// Short hash of the current git commit, if we are in a git repository
+pub fn current_commit() -> Option<String> {
+    let output = Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let commit = String::from_utf8(output.stdout).ok()?.trim().to_string();
+    (!commit.is_empty()).then_some(commit)
+}
+
+pub fn load_history(path: &Path) -> Result<Vec<HistoryEntry>> {
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+
+    let content = fs::read_to_string(path)
+        .with_context(|| format!("Could not read history from {}", path.display()))?;
+    parse_history(&content).with_context(|| format!("Invalid history file {}", path.display()))
+}
+
+fn parse_history(content: &str) -> Result<Vec<HistoryEntry>> {
+    content
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty() && *line != HISTORY_HEADER)
+        .map(|(index, line)| {
+            line.parse()
+                .map_err(|error| anyhow!("line {}: {}", index + 1, error))
+        })
+        .collect()
+}
+
+pub fn append_history(path: &Path, entries: &[HistoryEntry]) -> Result<()> {
+    let is_new = !path.exists();
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .with_context(|| format!("Could not open history file {}", path.display()))?;
+
+    let mut content = String::new();
+    if is_new {
+        content.push_str(HISTORY_HEADER);
+        content.push('\n');
+    }
+    for entry in entries {
+        content.push_str(&entry.to_csv());
+        content.push('\n');
+    }
+    file.write_all(content.as_bytes())
+        .with_context(|| format!("Could not write history to {}", path.display()))
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Comparison<'a> {
+    pub baseline: &'a HistoryEntry,
+    pub latest: &'a HistoryEntry,
+}
+
+impl Comparison<'_> {
+    // Relative change of the median time, e.g. 0.25 when 25% slower
+    pub fn change(&self) -> f64 {
+        let baseline = self.baseline.median.as_nanos() as f64;
+        let latest = self.latest.median.as_nanos() as f64;
+        if baseline == 0.0 {
+            return 0.0;
+        }
+        latest / baseline - 1.0
+    }
+
+    pub fn is_regression(&self, threshold: f64) -> bool {
+        self.change() > threshold
+    }
+}
+
+// Compares the latest run of every day, part and input with its previous run,
+// or with the latest run made at the given baseline commit
+pub fn compare<'a>(
+    history: &'a [HistoryEntry],
+    baseline_commit: Option<&str>,
+) -> Vec<Comparison<'a>> {
+    let mut by_key: BTreeMap<(&str, Part, &str), Vec<&HistoryEntry>> = BTreeMap::new();
+    for entry in history {
+        by_key
+            .entry((&entry.day, entry.part, &entry.input_name))
+            .or_default()
+            .push(entry);
+    }
+
+    by_key
+        .into_values()
+        .filter_map(|entries| {
+            let (latest, previous) = entries.split_last()?;
+            let baseline = previous.iter().rev().find(|entry| match baseline_commit {
+                Some(commit) => entry.commit.as_deref() == Some(commit),
+                None => true,
+            })?;
+            Some(Comparison { baseline, latest })
+        })
+        .collect()
+}
+
+pub fn parse_threshold(value: &str) -> Result<f64> {
+    let percent: f64 = value
+        .strip_suffix('%')
+        .unwrap_or(value)
+        .parse()
+        .map_err(|_| anyhow!("Invalid threshold: {} (e.g. 10%)", value))?;
+    if percent < 0.0 {
+        bail!("Threshold can not be negative: {}", value);
+    }
+    Ok(percent / 100.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(timestamp: u64, commit: &str, day: &str, part: Part, median_ns: u64) -> HistoryEntry {
+        let median = Duration::from_nanos(median_ns);
+        HistoryEntry {
+            timestamp,
+            commit: Some(commit.to_string()),
+            day: day.to_string(),
+            part,
+            input_name: format!("input-{}.txt", day),
+            runs: 10,
+            min: median,
+            median,
+            mean: median,
+            p95: median,
+        }
+    }
+
+    #[test]
+    fn should_write_and_parse_entries() {
+        let mut entry = entry(1733700000, "becb421", "09", Part::Two, 1500);
+        entry.input_name = "example,1.txt".to_string();
+        let no_commit = HistoryEntry {
+            commit: None,
+            ..entry.clone()
+        };
+
+        let content = format!(
+            "{}\n{}\n{}\n",
+            HISTORY_HEADER,
+            entry.to_csv(),
+            no_commit.to_csv()
+        );
+
+        assert_eq!(vec![entry, no_commit], parse_history(&content).unwrap());
+    }
+
+    #[test]
+    fn should_compare_latest_run_with_previous_one() {
+        let history = vec![
+            entry(1, "aaaaaaa", "09", Part::One, 100),
+            entry(1, "aaaaaaa", "09", Part::Two, 100),
+            entry(2, "bbbbbbb", "09", Part::One, 150),
+            entry(3, "ccccccc", "09", Part::One, 90),
+            entry(3, "ccccccc", "07", Part::One, 90),
+        ];
+
+        let comparisons = compare(&history, None);
+
+        assert_eq!(1, comparisons.len());
+        assert_eq!(&history[2], comparisons[0].baseline);
+        assert_eq!(&history[3], comparisons[0].latest);
+        assert!(!comparisons[0].is_regression(0.1));
+    }
+
+    #[test]
+    fn should_compare_with_baseline_commit() {
+        let history = vec![
+            entry(1, "aaaaaaa", "09", Part::One, 100),
+            entry(2, "bbbbbbb", "09", Part::One, 150),
+            entry(3, "ccccccc", "09", Part::One, 120),
+        ];
+
+        let comparisons = compare(&history, Some("aaaaaaa"));
+
+        assert_eq!(&history[0], comparisons[0].baseline);
+        assert!((comparisons[0].change() - 0.2).abs() < 1e-9);
+        assert!(comparisons[0].is_regression(0.1));
+        assert!(!comparisons[0].is_regression(0.25));
+    }
+
+    #[test]
+    fn should_parse_threshold() {
+        assert_eq!(0.1, parse_threshold("10%").unwrap());
+        assert_eq!(0.05, parse_threshold("5").unwrap());
+        assert!(parse_threshold("-5%").is_err());
+        assert!(parse_threshold("fast").is_err());
+    }
+}
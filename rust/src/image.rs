@@ -0,0 +1,485 @@
+use crate::grid::Grid;
+use anyhow::{bail, Result};
+use std::collections::HashMap;
+use std::path::Path;
+
+pub type Rgb = [u8; 3];
+
+// Colour of each tile type, tiles without a colour get the default one
+#[derive(Debug, Clone, PartialEq)]
+pub struct Palette {
+    colors: HashMap<char, Rgb>,
+    default: Rgb,
+}
+
+impl Default for Palette {
+    fn default() -> Self {
+        Self::new([255, 255, 255])
+            .with('.', [16, 16, 32])
+            .with('#', [128, 128, 128])
+    }
+}
+
+impl Palette {
+    pub fn new(default: Rgb) -> Self {
+        Self {
+            colors: HashMap::new(),
+            default,
+        }
+    }
+
+    pub fn with(mut self, tile: char, color: Rgb) -> Self {
+        self.colors.insert(tile, color);
+        self
+    }
+
+    pub fn with_all(mut self, tiles: &str, color: Rgb) -> Self {
+        for tile in tiles.chars() {
+            self.colors.insert(tile, color);
+        }
+        self
+    }
+
+    pub fn color_of(&self, tile: char) -> Rgb {
+        self.colors.get(&tile).copied().unwrap_or(self.default)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageFormat {
+    Ppm,
+    Png,
+    Gif,
+}
+
+impl ImageFormat {
+    pub fn from_path(path: &Path) -> Result<Self> {
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some("ppm") => Ok(ImageFormat::Ppm),
+            Some("png") => Ok(ImageFormat::Png),
+            Some("gif") => Ok(ImageFormat::Gif),
+            _ => bail!(
+                "Unknown image format for {} (expected .ppm, .png or .gif)",
+                path.display()
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<Rgb>,
+}
+
+impl Image {
+    // Every tile becomes a scale x scale square
+    pub fn from_tiles(tiles: &Grid<char>, palette: &Palette, scale: usize) -> Self {
+        let width = tiles.width() * scale;
+        let height = tiles.height() * scale;
+        let mut pixels = Vec::with_capacity(width * height);
+        for row in tiles.rows() {
+            let colors: Vec<Rgb> = row
+                .iter()
+                .flat_map(|tile| std::iter::repeat_n(palette.color_of(*tile), scale))
+                .collect();
+            for _ in 0..scale {
+                pixels.extend_from_slice(&colors);
+            }
+        }
+
+        Self {
+            width,
+            height,
+            pixels,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn to_ppm(&self) -> Vec<u8> {
+        let mut data = format!("P6\n{} {}\n255\n", self.width, self.height).into_bytes();
+        data.extend(self.pixels.iter().flatten());
+        data
+    }
+
+    pub fn to_png(&self) -> Vec<u8> {
+        let mut header = Vec::with_capacity(13);
+        header.extend((self.width as u32).to_be_bytes());
+        header.extend((self.height as u32).to_be_bytes());
+        // 8 bits per channel, RGB, default compression, filter and interlacing
+        header.extend([8, 2, 0, 0, 0]);
+
+        // Every scanline starts with its filter type, 0 being no filter
+        let mut scanlines = Vec::with_capacity(self.height * (1 + 3 * self.width));
+        for row in self.pixels.chunks(self.width.max(1)) {
+            scanlines.push(0);
+            scanlines.extend(row.iter().flatten());
+        }
+
+        let mut data = vec![0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1A, b'\n'];
+        write_png_chunk(&mut data, b"IHDR", &header);
+        write_png_chunk(&mut data, b"IDAT", &zlib_stored(&scanlines));
+        write_png_chunk(&mut data, b"IEND", &[]);
+        data
+    }
+}
+
+fn write_png_chunk(data: &mut Vec<u8>, kind: &[u8; 4], content: &[u8]) {
+    data.extend((content.len() as u32).to_be_bytes());
+    let start = data.len();
+    data.extend(kind);
+    data.extend(content);
+    let crc = crc32(&data[start..]);
+    data.extend(crc.to_be_bytes());
+}
+
+const CRC32_TABLE: [u32; 256] = {
+    let mut table = [0u32; 256];
+    let mut n = 0;
+    while n < 256 {
+        let mut crc = n as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 == 1 {
+                0xEDB8_8320 ^ (crc >> 1)
+            } else {
+                crc >> 1
+            };
+            bit += 1;
+        }
+        table[n] = crc;
+        n += 1;
+    }
+    table
+};
+
+fn crc32(data: &[u8]) -> u32 {
+    !data.iter().fold(!0u32, |crc, byte| {
+        CRC32_TABLE[((crc ^ *byte as u32) & 0xFF) as usize] ^ (crc >> 8)
+    })
+}
+
+fn adler32(data: &[u8]) -> u32 {
+    const MODULO: u32 = 65521;
+    let (a, b) = data.iter().fold((1u32, 0u32), |(a, b), byte| {
+        let a = (a + *byte as u32) % MODULO;
+        (a, (b + a) % MODULO)
+    });
+    (b << 16) | a
+}
+
+// Deflate stream made of uncompressed blocks: larger files, but no compressor to maintain
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    const MAX_BLOCK: usize = 65535;
+    let mut stream = vec![0x78, 0x01];
+    let blocks: Vec<&[u8]> = if data.is_empty() {
+        vec![&[]]
+    } else {
+        data.chunks(MAX_BLOCK).collect()
+    };
+    for (i, block) in blocks.iter().enumerate() {
+        let is_final = i == blocks.len() - 1;
+        stream.push(is_final as u8);
+        let length = block.len() as u16;
+        stream.extend(length.to_le_bytes());
+        stream.extend((!length).to_le_bytes());
+        stream.extend(*block);
+    }
+    stream.extend(adler32(data).to_be_bytes());
+    stream
+}
+
+// Looping animation sharing one global colour table, the frames must have the same size
+pub fn encode_gif(frames: &[Image], delay_centiseconds: u16) -> Result<Vec<u8>> {
+    let Some(first) = frames.first() else {
+        bail!("A GIF needs at least one frame");
+    };
+    if frames
+        .iter()
+        .any(|frame| frame.width != first.width || frame.height != first.height)
+    {
+        bail!("Every frame of a GIF must have the same size");
+    }
+    if first.width > u16::MAX as usize || first.height > u16::MAX as usize {
+        bail!(
+            "A {}x{} image is too large for a GIF",
+            first.width,
+            first.height
+        );
+    }
+
+    let mut colors: Vec<Rgb> = Vec::new();
+    let mut color_indices: HashMap<Rgb, u8> = HashMap::new();
+    let mut indexed_frames = Vec::with_capacity(frames.len());
+    for frame in frames {
+        let mut indices = Vec::with_capacity(frame.pixels.len());
+        for pixel in &frame.pixels {
+            let index = match color_indices.get(pixel) {
+                Some(index) => *index,
+                None => {
+                    if colors.len() == 256 {
+                        bail!("A GIF can not have more than 256 colours");
+                    }
+                    let index = colors.len() as u8;
+                    colors.push(*pixel);
+                    color_indices.insert(*pixel, index);
+                    index
+                }
+            };
+            indices.push(index);
+        }
+        indexed_frames.push(indices);
+    }
+
+    // The colour table has 2^bits entries, with at least 2 of them
+    let mut bits = 1;
+    while (1 << bits) < colors.len() {
+        bits += 1;
+    }
+    colors.resize(1 << bits, [0, 0, 0]);
+
+    let width = (first.width as u16).to_le_bytes();
+    let height = (first.height as u16).to_le_bytes();
+    let mut data = b"GIF89a".to_vec();
+    data.extend(width);
+    data.extend(height);
+    data.extend([0x80 | ((bits - 1) << 4) | (bits - 1), 0, 0]);
+    data.extend(colors.iter().flatten());
+    // Netscape extension to loop forever
+    data.extend([0x21, 0xFF, 0x0B]);
+    data.extend(b"NETSCAPE2.0");
+    data.extend([0x03, 0x01, 0x00, 0x00, 0x00]);
+
+    let min_code_size = bits.max(2);
+    for indices in indexed_frames {
+        data.extend([0x21, 0xF9, 0x04, 0x00]);
+        data.extend(delay_centiseconds.to_le_bytes());
+        data.extend([0x00, 0x00]);
+
+        data.push(0x2C);
+        data.extend([0, 0, 0, 0]);
+        data.extend(width);
+        data.extend(height);
+        data.push(0x00);
+
+        data.push(min_code_size);
+        for block in lzw_encode(&indices, min_code_size).chunks(255) {
+            data.push(block.len() as u8);
+            data.extend(block);
+        }
+        data.push(0x00);
+    }
+
+    data.push(0x3B);
+    Ok(data)
+}
+
+const MAX_CODE: u16 = 4095;
+
+#[derive(Default)]
+struct BitWriter {
+    bytes: Vec<u8>,
+    buffer: u32,
+    length: u8,
+}
+
+impl BitWriter {
+    // GIF codes are packed least significant bit first
+    fn write(&mut self, code: u16, size: u8) {
+        self.buffer |= (code as u32) << self.length;
+        self.length += size;
+        while self.length >= 8 {
+            self.bytes.push(self.buffer as u8);
+            self.buffer >>= 8;
+            self.length -= 8;
+        }
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        if self.length > 0 {
+            self.bytes.push(self.buffer as u8);
+        }
+        self.bytes
+    }
+}
+
+// Variable length LZW, starting over with a clear code once the 12 bits codes are exhausted
+fn lzw_encode(indices: &[u8], min_code_size: u8) -> Vec<u8> {
+    let clear_code = 1u16 << min_code_size;
+    let end_code = clear_code + 1;
+    let mut writer = BitWriter::default();
+    let mut dictionary: HashMap<(u16, u8), u16> = HashMap::new();
+    let mut next_code = end_code + 1;
+    let mut code_size = min_code_size + 1;
+
+    writer.write(clear_code, code_size);
+    let mut current: Option<u16> = None;
+    for index in indices {
+        let Some(prefix) = current else {
+            current = Some(*index as u16);
+            continue;
+        };
+        if let Some(code) = dictionary.get(&(prefix, *index)) {
+            current = Some(*code);
+            continue;
+        }
+
+        writer.write(prefix, code_size);
+        if next_code > MAX_CODE {
+            writer.write(clear_code, code_size);
+            dictionary.clear();
+            next_code = end_code + 1;
+            code_size = min_code_size + 1;
+        } else {
+            dictionary.insert((prefix, *index), next_code);
+            if next_code == 1 << code_size {
+                code_size += 1;
+            }
+            next_code += 1;
+        }
+        current = Some(*index as u16);
+    }
+
+    if let Some(prefix) = current {
+        writer.write(prefix, code_size);
+        // The decoder adds one last entry before reading the end code
+        if next_code == 1 << code_size && code_size < 12 {
+            code_size += 1;
+        }
+    }
+    writer.write(end_code, code_size);
+    writer.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decode_lzw(data: &[u8], min_code_size: u8) -> Vec<u8> {
+        let clear_code = 1u16 << min_code_size;
+        let end_code = clear_code + 1;
+        let mut dictionary: Vec<Vec<u8>> = Vec::new();
+        let mut code_size = min_code_size + 1;
+        let mut previous: Option<Vec<u8>> = None;
+        let mut output = Vec::new();
+        let (mut buffer, mut length, mut bytes) = (0u32, 0u8, data.iter());
+
+        loop {
+            while length < code_size {
+                buffer |= (*bytes.next().unwrap() as u32) << length;
+                length += 8;
+            }
+            let code = (buffer & ((1 << code_size) - 1)) as u16;
+            buffer >>= code_size;
+            length -= code_size;
+
+            if code == clear_code {
+                dictionary = (0..clear_code).map(|index| vec![index as u8]).collect();
+                dictionary.extend([Vec::new(), Vec::new()]);
+                code_size = min_code_size + 1;
+                previous = None;
+                continue;
+            }
+            if code == end_code {
+                return output;
+            }
+
+            let entry = match (dictionary.get(code as usize), &previous) {
+                (Some(entry), _) => entry.clone(),
+                (None, Some(previous)) => [previous.as_slice(), &previous[..1]].concat(),
+                (None, None) => panic!("unknown code {}", code),
+            };
+            output.extend(&entry);
+            if let Some(previous) = previous {
+                dictionary.push([previous.as_slice(), &entry[..1]].concat());
+                if dictionary.len() == 1 << code_size && code_size < 12 {
+                    code_size += 1;
+                }
+            }
+            previous = Some(entry);
+        }
+    }
+
+    #[test]
+    fn should_compute_checksums() {
+        assert_eq!(0xCBF4_3926, crc32(b"123456789"));
+        assert_eq!(0xAE42_6082, crc32(b"IEND"));
+        assert_eq!(0x11E6_0398, adler32(b"Wikipedia"));
+    }
+
+    #[test]
+    fn should_render_tiles_with_the_palette() {
+        let tiles: Grid<char> = "#.\n.@".parse().unwrap();
+        let palette = Palette::default().with('@', [255, 0, 0]);
+
+        let image = Image::from_tiles(&tiles, &palette, 2);
+
+        assert_eq!((4, 4), (image.width(), image.height()));
+        assert_eq!([128, 128, 128], image.pixels[1]);
+        assert_eq!([16, 16, 32], image.pixels[2]);
+        assert_eq!([255, 0, 0], image.pixels[15]);
+
+        let ppm = image.to_ppm();
+        assert!(ppm.starts_with(b"P6\n4 4\n255\n"));
+        assert_eq!(11 + 4 * 4 * 3, ppm.len());
+    }
+
+    #[test]
+    fn should_encode_png() {
+        let tiles: Grid<char> = "#.#\n...".parse().unwrap();
+        let png = Image::from_tiles(&tiles, &Palette::default(), 1).to_png();
+
+        assert_eq!(b"\x89PNG\r\n\x1a\n", &png[..8]);
+        assert_eq!(b"IHDR", &png[12..16]);
+        assert_eq!([0, 0, 0, 3, 0, 0, 0, 2, 8, 2, 0, 0, 0], png[16..29]);
+        assert_eq!(b"IEND\xae\x42\x60\x82", &png[png.len() - 8..]);
+
+        let stream = zlib_stored(&[7; 70000]);
+        assert_eq!([0x78, 0x01, 0x00, 0xFF, 0xFF, 0x00, 0x00], stream[..7]);
+        assert_eq!(2 + 2 * 5 + 70000 + 4, stream.len());
+    }
+
+    #[test]
+    fn should_round_trip_lzw() {
+        let mut indices: Vec<u8> = (0..20000u32).map(|i| ((i * i) % 7) as u8).collect();
+        indices.extend([3; 5000]);
+
+        assert_eq!(indices, decode_lzw(&lzw_encode(&indices, 3), 3));
+        assert_eq!(vec![1u8], decode_lzw(&lzw_encode(&[1], 2), 2));
+        assert!(decode_lzw(&lzw_encode(&[], 2), 2).is_empty());
+    }
+
+    #[test]
+    fn should_encode_gif() {
+        let palette = Palette::default();
+        let frames: Vec<Image> = ["#.\n..", ".#\n.."]
+            .into_iter()
+            .map(|tiles| Image::from_tiles(&tiles.parse().unwrap(), &palette, 1))
+            .collect();
+
+        let gif = encode_gif(&frames, 10).unwrap();
+
+        assert_eq!(b"GIF89a", &gif[..6]);
+        assert_eq!([2, 0, 2, 0, 0x80], gif[6..11]);
+        assert_eq!([128, 128, 128, 16, 16, 32], gif[13..19]);
+        assert_eq!(
+            2,
+            gif.windows(4)
+                .filter(|w| *w == [0x21, 0xF9, 0x04, 0x00])
+                .count()
+        );
+        assert_eq!(Some(&0x3B), gif.last());
+
+        let small = Image::from_tiles(&"#".parse().unwrap(), &palette, 1);
+        assert!(encode_gif(&[frames[0].clone(), small], 10).is_err());
+        assert!(encode_gif(&[], 10).is_err());
+    }
+}
@@ -0,0 +1,126 @@
+use anyhow::{anyhow, Context, Result};
+use std::env;
+use std::fs::read_to_string;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+pub const DATA_DIR_ENV: &str = "AOC_DATA_DIR";
+const DATA_DIR_NAME: &str = "data";
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum InputSource {
+    #[default]
+    DataDir,
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    pub fn from_arg(value: &str) -> Self {
+        match value {
+            "-" => InputSource::Stdin,
+            path => InputSource::File(PathBuf::from(path)),
+        }
+    }
+
+    pub fn name(&self, day: &str) -> Option<String> {
+        match self {
+            InputSource::DataDir => Some(input_file_name(day)),
+            InputSource::File(path) => path
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned()),
+            InputSource::Stdin => None,
+        }
+    }
+}
+
+pub fn input_file_name(day: &str) -> String {
+    format!("input-{}.txt", day)
+}
+
+fn search_data_dir(start: &Path) -> Option<PathBuf> {
+    start
+        .ancestors()
+        .map(|dir| dir.join(DATA_DIR_NAME))
+        .find(|dir| dir.is_dir())
+}
+
+pub fn find_data_dir() -> Result<PathBuf> {
+    if let Some(data_dir) = env::var_os(DATA_DIR_ENV) {
+        return Ok(PathBuf::from(data_dir));
+    }
+
+    let current_dir = env::current_dir().context("Could not get the current directory")?;
+    search_data_dir(&current_dir).ok_or_else(|| {
+        anyhow!(
+            "No '{}' directory found in {} or its parents (set {} or use --input <file>)",
+            DATA_DIR_NAME,
+            current_dir.display(),
+            DATA_DIR_ENV
+        )
+    })
+}
+
+fn read_file(path: &Path) -> Result<String> {
+    read_to_string(path).with_context(|| format!("Could not read input file {}", path.display()))
+}
+
+pub fn read_input(day: &str, source: &InputSource) -> Result<String> {
+    match source {
+        InputSource::DataDir => read_file(&find_data_dir()?.join(input_file_name(day))),
+        InputSource::File(path) => read_file(path),
+        InputSource::Stdin => {
+            let mut input_data = String::new();
+            io::stdin()
+                .read_to_string(&mut input_data)
+                .context("Could not read input from stdin")?;
+            Ok(input_data)
+        }
+    }
+}
+
+pub fn get_input_data(day: &str) -> Result<String> {
+    read_input(day, &InputSource::DataDir)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn should_find_data_dir_in_parents() {
+        let root = env::temp_dir().join(format!("aoc-input-{}", std::process::id()));
+        let data_dir = root.join(DATA_DIR_NAME);
+        let nested_dir = root.join("rust").join("src");
+        fs::create_dir_all(&data_dir).unwrap();
+        fs::create_dir_all(&nested_dir).unwrap();
+
+        let found = search_data_dir(&nested_dir);
+        fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(Some(data_dir), found);
+    }
+
+    #[test]
+    fn should_parse_input_source_from_arg() {
+        assert_eq!(InputSource::Stdin, InputSource::from_arg("-"));
+        assert_eq!(
+            InputSource::File(PathBuf::from("example.txt")),
+            InputSource::from_arg("example.txt")
+        );
+    }
+
+    #[test]
+    fn should_name_input_after_its_file() {
+        assert_eq!(
+            Some("input-07.txt".to_string()),
+            InputSource::DataDir.name("07")
+        );
+        assert_eq!(
+            Some("example.txt".to_string()),
+            InputSource::from_arg("../examples/example.txt").name("07")
+        );
+        assert_eq!(None, InputSource::Stdin.name("07"));
+    }
+}
@@ -1,14 +1,131 @@
-use std::fs::read_to_string;
-use std::io;
+use crate::simulation::Simulation;
+use std::fmt::Display;
 use std::time::Duration;
 
-pub fn start_day(day: &str) {
-    println!("Advent of Code 2024 - Day {:0>2}", day);
+pub mod answers;
+pub mod bench;
+pub mod crosscheck;
+pub mod days;
+pub mod fixtures;
+pub mod grid;
+pub mod history;
+pub mod image;
+pub mod input;
+pub mod parse;
+pub mod position;
+pub mod report;
+pub mod runner;
+pub mod scaffold;
+pub mod search;
+pub mod simulation;
+
+pub use input::get_input_data;
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Copy, Clone)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Verdict {
+    Valid,
+    Invalid { expected: String, actual: String },
+    Unknown,
+}
+
+impl Verdict {
+    pub fn new(actual: &str, expected: Option<&str>) -> Self {
+        match expected {
+            None => Verdict::Unknown,
+            Some(expected) if expected == actual => Verdict::Valid,
+            Some(expected) => Verdict::Invalid {
+                expected: expected.to_string(),
+                actual: actual.to_string(),
+            },
+        }
+    }
+
+    pub fn is_invalid(&self) -> bool {
+        matches!(self, Verdict::Invalid { .. })
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Verdict::Valid => "valid",
+            Verdict::Invalid { .. } => "invalid",
+            Verdict::Unknown => "unknown",
+        }
+    }
 }
 
-pub fn get_input_data(day: &str) -> io::Result<String> {
-    let filename = format!("../data/input-{}.txt", day);
-    read_to_string(filename)
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Valid => write!(f, "✅ Solution is valid!"),
+            Verdict::Invalid { expected, actual } => write!(
+                f,
+                "❌ Solution is invalid! (expected: {}, actual: {})",
+                expected, actual
+            ),
+            Verdict::Unknown => write!(f, "⚠️ No known solution... Can not check"),
+        }
+    }
+}
+
+// Command line options that only some days use, like the detector of day 14
+#[derive(Debug, Default, PartialEq, Eq, Clone)]
+pub struct DayOptions {
+    pub detector: Option<String>,
+}
+
+pub trait Solution {
+    const DAY: &'static str;
+
+    type Input;
+
+    fn parse_input(input_data: &str) -> anyhow::Result<Self::Input>;
+
+    fn solve_part_1(input: &Self::Input) -> anyhow::Result<String>;
+
+    fn solve_part_2(input: &Self::Input) -> anyhow::Result<String>;
+
+    fn solve(part: Part, input: &Self::Input) -> anyhow::Result<String> {
+        match part {
+            Part::One => Self::solve_part_1(input),
+            Part::Two => Self::solve_part_2(input),
+        }
+    }
+
+    // Applies the command line options to a parsed input, days without options reject them
+    fn configure(_input: &mut Self::Input, options: &DayOptions) -> anyhow::Result<()> {
+        if options.detector.is_some() {
+            anyhow::bail!("Day {} has no detector", Self::DAY);
+        }
+        Ok(())
+    }
+
+    // Days solved by stepping through a simulation can expose it to the visualizer
+    fn simulation(_input: &Self::Input, _part: Part) -> Option<Box<dyn Simulation + '_>> {
+        None
+    }
+}
+
+pub fn start_day(day: &str) {
+    println!("Advent of Code 2024 - Day {:0>2}", day);
 }
 
 #[macro_export]
@@ -41,16 +158,26 @@ pub fn pretty_duration(duration: Duration) -> String {
     )
 }
 
-pub fn check_result(actual: &str, expected: &str) {
-    if actual != expected {
-        match expected {
-            "TODO" => println!("⚠️ No known solution... Can not check"),
-            _ => println!(
-                "❌ Solution is invalid! (expected: {}, actual: {})",
-                expected, actual
-            ),
-        }
-        return;
+pub fn check_result(actual: &str, expected: Option<&str>) -> Verdict {
+    let verdict = Verdict::new(actual, expected);
+    println!("{}", verdict);
+    verdict
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_check_result() {
+        assert_eq!(Verdict::Valid, check_result("42", Some("42")));
+        assert_eq!(Verdict::Unknown, check_result("42", None));
+        assert_eq!(
+            Verdict::Invalid {
+                expected: "41".to_string(),
+                actual: "42".to_string()
+            },
+            check_result("42", Some("41"))
+        );
     }
-    println!("✅ Solution is valid!")
 }
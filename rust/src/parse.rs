@@ -0,0 +1,520 @@
+use crate::grid::Grid;
+use std::fmt::Display;
+use std::str::FromStr;
+
+const MAX_SNIPPET_LENGTH: usize = 32;
+
+// Malformed input, lines and columns are 1-based and columns count chars
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: Option<String>,
+    pub line: usize,
+    pub column: usize,
+    pub snippet: String,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, snippet: &str, message: impl Into<String>) -> Self {
+        Self {
+            day: None,
+            line,
+            column,
+            snippet: snippet.to_string(),
+            message: message.into(),
+        }
+    }
+
+    pub fn with_day(mut self, day: &str) -> Self {
+        self.day = Some(day.to_string());
+        self
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(day) = &self.day {
+            write!(f, "Day {}, ", day)?;
+        }
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )?;
+        if self.snippet.is_empty() {
+            write!(f, " (at end of line)")
+        } else if self.snippet.chars().count() > MAX_SNIPPET_LENGTH {
+            let snippet: String = self.snippet.chars().take(MAX_SNIPPET_LENGTH).collect();
+            write!(f, " (got {:?}...)", snippet)
+        } else {
+            write!(f, " (got {:?})", self.snippet)
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+// A line of a puzzle input, knowing where it is so that errors can point at it
+#[derive(Debug, Clone, Copy)]
+pub struct InputLine<'a> {
+    pub day: &'a str,
+    pub number: usize,
+    pub text: &'a str,
+}
+
+// Lines of the input, numbered from 1, without the '\r' of CRLF line endings
+pub fn input_lines<'a>(day: &'a str, input: &'a str) -> impl Iterator<Item = InputLine<'a>> {
+    // Like str::lines, a final line ending does not start a new line
+    let body = input.strip_suffix('\n').unwrap_or(input);
+    (!input.is_empty())
+        .then_some(body)
+        .into_iter()
+        .flat_map(|body| body.split('\n'))
+        .enumerate()
+        .map(move |(index, text)| InputLine {
+            day,
+            number: index + 1,
+            text: text.strip_suffix('\r').unwrap_or(text),
+        })
+}
+
+impl<'a> InputLine<'a> {
+    pub fn is_blank(&self) -> bool {
+        self.text.trim().is_empty()
+    }
+
+    // Column of a slice of the line, or of the end of the line for foreign strings
+    fn column_of(&self, at: &str) -> usize {
+        let start = self.text.as_ptr() as usize;
+        let offset = (at.as_ptr() as usize)
+            .checked_sub(start)
+            .filter(|offset| *offset <= self.text.len())
+            .unwrap_or(self.text.len());
+        self.text[..offset].chars().count() + 1
+    }
+
+    // Error pointing at `at`, which should be a slice of the line
+    pub fn error(&self, at: &str, message: impl Into<String>) -> ParseError {
+        let error = ParseError::new(self.number, self.column_of(at), at, message);
+        match self.day {
+            "" => error,
+            day => error.with_day(day),
+        }
+    }
+
+    pub fn error_at_end(&self, message: impl Into<String>) -> ParseError {
+        self.error(&self.text[self.text.len()..], message)
+    }
+
+    pub fn parse<T>(&self, field: &str) -> Result<T, ParseError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        field
+            .parse()
+            .map_err(|error| self.error(field, format!("invalid value: {}", error)))
+    }
+
+    pub fn split_once(
+        &self,
+        s: &'a str,
+        delimiter: &str,
+    ) -> Result<(&'a str, &'a str), ParseError> {
+        s.split_once(delimiter)
+            .ok_or_else(|| self.error(s, format!("expected {:?}", delimiter)))
+    }
+
+    pub fn strip_prefix(&self, s: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+        s.strip_prefix(prefix)
+            .ok_or_else(|| self.error(s, format!("expected {:?}", prefix)))
+    }
+
+    // Next field of an iterator over slices of the line, an error at the end of the line if none
+    pub fn next_field(
+        &self,
+        fields: &mut impl Iterator<Item = &'a str>,
+        name: &str,
+    ) -> Result<&'a str, ParseError> {
+        fields
+            .next()
+            .ok_or_else(|| self.error_at_end(format!("missing {}", name)))
+    }
+
+    pub fn expect_end(&self, fields: &mut impl Iterator<Item = &'a str>) -> Result<(), ParseError> {
+        match fields.next() {
+            Some(extra) => Err(self.error(extra, "unexpected trailing value")),
+            None => Ok(()),
+        }
+    }
+
+    // The signed integers of a slice of the line, e.g. [3, -3] for "3,-3". Anything but whitespace
+    // and separators around them, like "1a", "1.9" or the "x" of "10 x 19", is an error
+    pub fn integers<T>(&self, s: &'a str) -> Result<Vec<T>, ParseError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.integers_separated_by(s, SEPARATORS)
+    }
+
+    pub fn integer_array<T, const N: usize>(&self, s: &'a str) -> Result<[T; N], ParseError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.integer_array_separated_by(s, SEPARATORS)
+    }
+
+    // Same as integer_array with other separators, e.g. [", Y="] for "8400, Y=5400"
+    pub fn integer_array_separated_by<T, const N: usize>(
+        &self,
+        s: &'a str,
+        separators: &[&str],
+    ) -> Result<[T; N], ParseError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let integers = self.integers_separated_by(s, separators)?;
+        let count = integers.len();
+        integers
+            .try_into()
+            .map_err(|_| self.error(s, format!("expected {} integers, found {}", N, count)))
+    }
+
+    fn integers_separated_by<T>(
+        &self,
+        s: &'a str,
+        separators: &[&str],
+    ) -> Result<Vec<T>, ParseError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        find_integers(s, separators)
+            .map_err(|stray| self.error(stray, "invalid value: expected an integer"))?
+            .into_iter()
+            .map(|integer| self.parse(integer))
+            .collect()
+    }
+
+    // Whitespace separated "key=value" pairs, e.g. [("p", "0,4"), ("v", "3,-3")] for "p=0,4 v=3,-3"
+    pub fn key_values(&self, s: &'a str) -> Result<Vec<(&'a str, &'a str)>, ParseError> {
+        s.split_whitespace()
+            .map(|pair| self.split_once(pair, "="))
+            .collect()
+    }
+
+    pub fn value_of(&self, s: &'a str, key: &str) -> Result<&'a str, ParseError> {
+        self.key_values(s)?
+            .into_iter()
+            .find(|(name, _)| *name == key)
+            .map(|(_, value)| value)
+            .ok_or_else(|| self.error(s, format!("missing {}=", key)))
+    }
+}
+
+// Separators allowed between integers on top of whitespace, like in "0,4", "47|53" or "1-3"
+const SEPARATORS: &[&str] = &[",", ";", ":", "|", "..", "-"];
+
+// Slices of the integers of a string, separated by whitespace and at most one separator. A '-'
+// right after an integer is a separator, and a sign anywhere else. Any other text is an error
+// holding the offending word
+fn find_integers<'a>(s: &'a str, separators: &[&str]) -> Result<Vec<&'a str>, &'a str> {
+    let mut integers = Vec::new();
+    let mut rest = s.trim_start();
+    while !rest.is_empty() {
+        let sign = usize::from(rest.starts_with('-'));
+        let digits = rest[sign..]
+            .find(|ch: char| !ch.is_ascii_digit())
+            .unwrap_or(rest.len() - sign);
+        if digits == 0 {
+            return Err(stray_word(rest, separators));
+        }
+        let (integer, after) = rest.split_at(sign + digits);
+        integers.push(integer);
+
+        let trimmed = after.trim_start();
+        let separator = if trimmed.len() == after.len() {
+            separators
+                .iter()
+                .find(|separator| after.starts_with(**separator))
+        } else {
+            let is_sign = trimmed.starts_with('-')
+                && trimmed[1..].starts_with(|ch: char| ch.is_ascii_digit());
+            separators
+                .iter()
+                .filter(|_| !is_sign)
+                .find(|separator| trimmed.starts_with(**separator))
+        };
+        rest = match separator {
+            Some(separator) => {
+                let next = trimmed[separator.len()..].trim_start();
+                if next.is_empty() {
+                    return Err(next);
+                }
+                next
+            }
+            None if trimmed.len() == after.len() && !after.is_empty() => {
+                return Err(stray_word(rest, separators));
+            }
+            None => trimmed,
+        };
+    }
+    Ok(integers)
+}
+
+// The text from the start of s to the next whitespace or separator, at least one char long
+fn stray_word<'a>(s: &'a str, separators: &[&str]) -> &'a str {
+    let end = s
+        .char_indices()
+        .skip(1)
+        .find(|(offset, ch)| {
+            ch.is_whitespace()
+                || separators
+                    .iter()
+                    .any(|separator| s[*offset..].starts_with(separator))
+        })
+        .map_or(s.len(), |(offset, _)| offset);
+    &s[..end]
+}
+
+// Groups of consecutive non-blank lines, like the map and the moves of day 15
+pub fn blocks<'a>(day: &'a str, input: &'a str) -> Vec<Vec<InputLine<'a>>> {
+    let mut blocks = Vec::new();
+    let mut block = Vec::new();
+    for line in input_lines(day, input) {
+        if line.is_blank() {
+            if !block.is_empty() {
+                blocks.push(std::mem::take(&mut block));
+            }
+        } else {
+            block.push(line);
+        }
+    }
+    if !block.is_empty() {
+        blocks.push(block);
+    }
+    blocks
+}
+
+// Every trimmed line is a row and every char is mapped to a cell, rows must have the same width
+pub fn parse_grid<T>(
+    lines: &[InputLine],
+    mut mapping: impl FnMut(char) -> anyhow::Result<T>,
+) -> Result<Grid<T>, ParseError> {
+    let width = lines
+        .first()
+        .map_or(0, |line| line.text.trim().chars().count());
+
+    let mut cells = Vec::with_capacity(width * lines.len());
+    for line in lines {
+        let row = line.text.trim();
+        let start = cells.len();
+        for (offset, ch) in row.char_indices() {
+            let cell = mapping(ch).map_err(|error| {
+                line.error(&row[offset..offset + ch.len_utf8()], format!("{:#}", error))
+            })?;
+            cells.push(cell);
+        }
+        let row_width = cells.len() - start;
+        if row_width != width {
+            let at = row
+                .char_indices()
+                .nth(width)
+                .map_or(&row[row.len()..], |(offset, _)| &row[offset..]);
+            return Err(line.error(
+                at,
+                format!("expected {} columns (got {})", width, row_width),
+            ));
+        }
+    }
+
+    Ok(Grid::from_cells(width, lines.len(), cells).expect("every row has the same width"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_number_lines_and_strip_crlf() {
+        let lines: Vec<(usize, &str)> = input_lines("01", "a\r\n\r\nb\r\n")
+            .map(|line| (line.number, line.text))
+            .collect();
+
+        assert_eq!(vec![(1, "a"), (2, ""), (3, "b")], lines);
+        assert_eq!(2, input_lines("01", "a\nb").count());
+        assert_eq!(0, input_lines("01", "").count());
+    }
+
+    #[test]
+    fn should_point_at_the_offending_value() {
+        let line = input_lines("07", "190: 10 1a\n").next().unwrap();
+        let (_, values) = line.split_once(line.text, ": ").unwrap();
+        let values: Vec<&str> = values.split(' ').collect();
+
+        let error = line.parse::<u64>(values[1]).unwrap_err();
+
+        assert_eq!(
+            ParseError {
+                day: Some("07".to_string()),
+                line: 1,
+                column: 9,
+                snippet: "1a".to_string(),
+                message: "invalid value: invalid digit found in string".to_string(),
+            },
+            error
+        );
+        assert_eq!(
+            "Day 07, line 1, column 9: invalid value: invalid digit found in string (got \"1a\")",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn should_point_at_the_end_of_truncated_lines() {
+        let line = input_lines("14", "ok\np=0,4 v=3").nth(1).unwrap();
+        let mut fields = line.text.split(',');
+        fields.next();
+        fields.next();
+
+        let error = line.next_field(&mut fields, "velocity y").unwrap_err();
+
+        assert_eq!(
+            "Day 14, line 2, column 10: missing velocity y (at end of line)",
+            error.to_string()
+        );
+        assert!(line.split_once(line.text, ": ").is_err());
+    }
+
+    #[test]
+    fn should_extract_signed_integers() {
+        let line = input_lines("05", "3, -4; 47|53 7..9 1-3 -5 - 6:-7")
+            .next()
+            .unwrap();
+
+        assert_eq!(
+            vec![3, -4, 47, 53, 7, 9, 1, 3, -5, 6, -7],
+            line.integers::<i64>(line.text).unwrap()
+        );
+        assert_eq!(
+            [3, -4],
+            line.integer_array::<i64, 2>(&line.text[..5]).unwrap()
+        );
+
+        let error = line.integer_array::<i64, 3>(&line.text[..5]).unwrap_err();
+        assert_eq!("expected 3 integers, found 2", error.message);
+        assert!(line.integers::<u32>(line.text).is_err());
+        assert_eq!(Vec::<i64>::new(), line.integers(&line.text[..0]).unwrap());
+    }
+
+    #[test]
+    fn should_extract_integers_with_other_separators() {
+        let line = input_lines("13", "Prize: X=8400, Y=5400").next().unwrap();
+
+        assert_eq!(
+            [8400, 5400],
+            line.integer_array_separated_by::<i64, 2>(&line.text[9..], &[", Y="])
+                .unwrap()
+        );
+        let error = line
+            .integer_array_separated_by::<i64, 2>(&line.text[9..], &[", Y+"])
+            .unwrap_err();
+        assert_eq!((10, "8400,"), (error.column, error.snippet.as_str()));
+    }
+
+    #[test]
+    fn should_reject_integers_followed_by_stray_chars() {
+        let line = input_lines("07", "190: 10 1a").next().unwrap();
+        let error = line.integers::<u64>(line.text).unwrap_err();
+        assert_eq!(
+            "Day 07, line 1, column 9: invalid value: expected an integer (got \"1a\")",
+            error.to_string()
+        );
+
+        let line = input_lines("07", "190: 10 1.9").next().unwrap();
+        let error = line.integers::<u64>(line.text).unwrap_err();
+        assert_eq!((9, "1.9"), (error.column, error.snippet.as_str()));
+
+        let line = input_lines("14", "p=1x,2 v=1,1").next().unwrap();
+        let position = line.value_of(line.text, "p").unwrap();
+        assert!(line.integer_array::<i64, 2>(position).is_err());
+    }
+
+    #[test]
+    fn should_reject_stray_text_around_integers() {
+        for (text, column, snippet) in [
+            ("190: 10 x 19", 9, "x"),
+            ("x0,4", 1, "x0"),
+            ("(3, 4)", 1, "(3"),
+            ("1,,2", 3, ",2"),
+            ("1, 2,", 6, ""),
+        ] {
+            let line = input_lines("07", text).next().unwrap();
+            let error = line.integers::<i64>(line.text).unwrap_err();
+            assert_eq!(
+                (column, snippet),
+                (error.column, error.snippet.as_str()),
+                "{}",
+                text
+            );
+        }
+    }
+
+    #[test]
+    fn should_parse_key_values() {
+        let line = input_lines("14", "p=0,4 v=3,-3").next().unwrap();
+
+        assert_eq!(
+            vec![("p", "0,4"), ("v", "3,-3")],
+            line.key_values(line.text).unwrap()
+        );
+        assert_eq!("3,-3", line.value_of(line.text, "v").unwrap());
+        assert!(line.value_of(line.text, "q").is_err());
+
+        let line = input_lines("14", "p=0,4 v").next().unwrap();
+        assert_eq!(7, line.key_values(line.text).unwrap_err().column);
+    }
+
+    #[test]
+    fn should_split_blocks() {
+        let blocks = blocks("15", "##\n#@\n\n\n<>\r\n^v\n\n");
+
+        assert_eq!(2, blocks.len());
+        assert_eq!(
+            vec![1, 2],
+            blocks[0].iter().map(|line| line.number).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec!["<>", "^v"],
+            blocks[1].iter().map(|line| line.text).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn should_parse_grid_with_line_numbers() {
+        let blocks = blocks("15", "moves\n\n##\n#@\n");
+        let grid = parse_grid(&blocks[1], Ok).unwrap();
+
+        assert_eq!("##\n#@", grid.to_string());
+
+        let error = parse_grid(&blocks[1], |ch| match ch {
+            '#' | '.' => Ok(ch),
+            _ => anyhow::bail!("expected '#' or '.'"),
+        })
+        .unwrap_err();
+        assert_eq!(
+            "Day 15, line 4, column 2: expected '#' or '.' (got \"@\")",
+            error.to_string()
+        );
+
+        let lines = lines_of("##\n###");
+        assert_eq!(3, parse_grid(&lines, Ok).unwrap_err().column);
+    }
+
+    fn lines_of(input: &str) -> Vec<InputLine<'_>> {
+        input_lines("", input).collect()
+    }
+}
@@ -0,0 +1,222 @@
+use std::fmt::Display;
+use std::ops::{Add, AddAssign, Sub, SubAssign};
+
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Position {
+    pub row: isize,
+    pub col: isize,
+}
+
+// Offsets of the 8 surrounding positions, clockwise from north
+const NEIGHBOUR_OFFSETS_8: [Position; 8] = [
+    Position::new(-1, 0),
+    Position::new(-1, 1),
+    Position::new(0, 1),
+    Position::new(1, 1),
+    Position::new(1, 0),
+    Position::new(1, -1),
+    Position::new(0, -1),
+    Position::new(-1, -1),
+];
+
+impl Position {
+    pub const fn new(row: isize, col: isize) -> Self {
+        Self { row, col }
+    }
+
+    pub fn step(self, direction: Direction) -> Position {
+        self + direction.offset()
+    }
+
+    // Unbounded, in the order of Direction::ALL
+    pub fn neighbours_4(self) -> [Position; 4] {
+        Direction::ALL.map(|direction| self.step(direction))
+    }
+
+    // Unbounded, clockwise from north
+    pub fn neighbours_8(self) -> [Position; 8] {
+        NEIGHBOUR_OFFSETS_8.map(|offset| self + offset)
+    }
+
+    // Wraps the position around a torus of the given size
+    pub fn wrap(self, width: usize, height: usize) -> Position {
+        Position::new(
+            self.row.rem_euclid(height as isize),
+            self.col.rem_euclid(width as isize),
+        )
+    }
+
+    pub fn wrapping_neighbours_4(self, width: usize, height: usize) -> [Position; 4] {
+        self.neighbours_4()
+            .map(|neighbour| neighbour.wrap(width, height))
+    }
+
+    pub fn wrapping_neighbours_8(self, width: usize, height: usize) -> [Position; 8] {
+        self.neighbours_8()
+            .map(|neighbour| neighbour.wrap(width, height))
+    }
+
+    pub fn manhattan_distance(self, other: Position) -> usize {
+        self.row.abs_diff(other.row) + self.col.abs_diff(other.col)
+    }
+}
+
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    #[default]
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    // Clockwise from up
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    pub fn offset(self) -> Position {
+        match self {
+            Direction::Up => Position::new(-1, 0),
+            Direction::Right => Position::new(0, 1),
+            Direction::Down => Position::new(1, 0),
+            Direction::Left => Position::new(0, -1),
+        }
+    }
+
+    pub fn turn_right(self) -> Direction {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+        }
+    }
+
+    pub fn turn_left(self) -> Direction {
+        match self {
+            Direction::Up => Direction::Left,
+            Direction::Right => Direction::Up,
+            Direction::Down => Direction::Right,
+            Direction::Left => Direction::Down,
+        }
+    }
+
+    pub fn opposite(self) -> Direction {
+        self.turn_right().turn_right()
+    }
+
+    // The direction of a '^', '>', 'v' or '<' marker
+    pub fn from_arrow(arrow: char) -> Option<Direction> {
+        match arrow {
+            '^' => Some(Direction::Up),
+            '>' => Some(Direction::Right),
+            'v' => Some(Direction::Down),
+            '<' => Some(Direction::Left),
+            _ => None,
+        }
+    }
+
+    pub fn arrow(self) -> char {
+        match self {
+            Direction::Up => '^',
+            Direction::Right => '>',
+            Direction::Down => 'v',
+            Direction::Left => '<',
+        }
+    }
+}
+
+impl Add for Position {
+    type Output = Position;
+
+    fn add(self, other: Position) -> Position {
+        Position::new(self.row + other.row, self.col + other.col)
+    }
+}
+
+impl AddAssign for Position {
+    fn add_assign(&mut self, other: Position) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Position {
+    type Output = Position;
+
+    fn sub(self, other: Position) -> Position {
+        Position::new(self.row - other.row, self.col - other.col)
+    }
+}
+
+impl SubAssign for Position {
+    fn sub_assign(&mut self, other: Position) {
+        *self = *self - other;
+    }
+}
+
+impl Display for Position {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.row, self.col)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_list_neighbours() {
+        let position = Position::new(0, 0);
+
+        assert_eq!(
+            [
+                Position::new(-1, 0),
+                Position::new(0, 1),
+                Position::new(1, 0),
+                Position::new(0, -1)
+            ],
+            position.neighbours_4()
+        );
+        assert_eq!(Position::new(-1, 1), position.neighbours_8()[1]);
+        assert_eq!(Position::new(-1, -1), position.neighbours_8()[7]);
+    }
+
+    #[test]
+    fn should_wrap_neighbours() {
+        let position = Position::new(0, 10);
+
+        assert_eq!(
+            [
+                Position::new(6, 10),
+                Position::new(0, 0),
+                Position::new(1, 10),
+                Position::new(0, 9)
+            ],
+            position.wrapping_neighbours_4(11, 7)
+        );
+        assert_eq!(Position::new(4, 1), Position::new(-3, 23).wrap(11, 7));
+    }
+
+    #[test]
+    fn should_turn() {
+        assert_eq!(Direction::Right, Direction::Up.turn_right());
+        assert_eq!(Direction::Left, Direction::Up.turn_left());
+        assert_eq!(Direction::Down, Direction::Up.opposite());
+        assert_eq!(None, Direction::from_arrow('x'));
+        for direction in Direction::ALL {
+            assert_eq!(direction, direction.turn_left().turn_right());
+            assert_eq!(Some(direction), Direction::from_arrow(direction.arrow()));
+            assert_eq!(
+                Position::default(),
+                Position::default()
+                    .step(direction)
+                    .step(direction.opposite())
+            );
+        }
+    }
+}
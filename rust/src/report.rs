@@ -0,0 +1,183 @@
+use crate::bench::Stats;
+use crate::{Part, Verdict};
+use anyhow::bail;
+use std::str::FromStr;
+use std::time::Duration;
+
+#[derive(Debug, PartialEq, Copy, Clone, Default)]
+pub enum ReportFormat {
+    #[default]
+    Text,
+    Json,
+    Csv,
+}
+
+impl FromStr for ReportFormat {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(ReportFormat::Text),
+            "json" => Ok(ReportFormat::Json),
+            "csv" => Ok(ReportFormat::Csv),
+            _ => bail!("Invalid format: {} (expected text, json or csv)", s),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct PartReport {
+    pub day: String,
+    pub part: Part,
+    pub answer: String,
+    pub expected: Option<String>,
+    pub verdict: Verdict,
+    pub parse_duration: Duration,
+    pub solve_duration: Duration,
+    pub stats: Option<Stats>,
+}
+
+pub const CSV_HEADER: &str = "day,part,answer,expected,verdict,parse_time_ns,solve_time_ns,runs,min_ns,median_ns,mean_ns,stddev_ns,p95_ns";
+
+fn escape_json(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len() + 2);
+    escaped.push('"');
+    for char in value.chars() {
+        match char {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if c.is_control() => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+fn escape_csv(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+impl PartReport {
+    pub fn to_json(&self) -> String {
+        let expected = match &self.expected {
+            Some(expected) => escape_json(expected),
+            None => "null".to_string(),
+        };
+
+        let stats = match &self.stats {
+            Some(stats) => format!(
+                ",\"runs\":{},\"min_ns\":{},\"median_ns\":{},\"mean_ns\":{},\"stddev_ns\":{},\"p95_ns\":{}",
+                stats.runs,
+                stats.min.as_nanos(),
+                stats.median.as_nanos(),
+                stats.mean.as_nanos(),
+                stats.stddev.as_nanos(),
+                stats.p95.as_nanos()
+            ),
+            None => String::new(),
+        };
+
+        format!(
+            "{{\"day\":{},\"part\":{},\"answer\":{},\"expected\":{},\"verdict\":{},\"parse_time_ns\":{},\"solve_time_ns\":{}{}}}",
+            escape_json(&self.day),
+            self.part,
+            escape_json(&self.answer),
+            expected,
+            escape_json(self.verdict.as_str()),
+            self.parse_duration.as_nanos(),
+            self.solve_duration.as_nanos(),
+            stats
+        )
+    }
+
+    pub fn to_csv(&self) -> String {
+        let stats = match &self.stats {
+            Some(stats) => format!(
+                "{},{},{},{},{},{}",
+                stats.runs,
+                stats.min.as_nanos(),
+                stats.median.as_nanos(),
+                stats.mean.as_nanos(),
+                stats.stddev.as_nanos(),
+                stats.p95.as_nanos()
+            ),
+            None => ",,,,,".to_string(),
+        };
+
+        format!(
+            "{},{},{},{},{},{},{},{}",
+            escape_csv(&self.day),
+            self.part,
+            escape_csv(&self.answer),
+            escape_csv(self.expected.as_deref().unwrap_or("")),
+            self.verdict.as_str(),
+            self.parse_duration.as_nanos(),
+            self.solve_duration.as_nanos(),
+            stats
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_report() -> PartReport {
+        PartReport {
+            day: "07".to_string(),
+            part: Part::Two,
+            answer: "11387".to_string(),
+            expected: None,
+            verdict: Verdict::Unknown,
+            parse_duration: Duration::from_nanos(1500),
+            solve_duration: Duration::from_micros(42),
+            stats: None,
+        }
+    }
+
+    #[test]
+    fn should_format_report_as_json() {
+        let expected = "{\"day\":\"07\",\"part\":2,\"answer\":\"11387\",\"expected\":null,\"verdict\":\"unknown\",\"parse_time_ns\":1500,\"solve_time_ns\":42000}";
+
+        assert_eq!(expected, get_report().to_json());
+    }
+
+    #[test]
+    fn should_format_report_as_csv() {
+        let expected = "07,2,11387,,unknown,1500,42000,,,,,,";
+
+        assert_eq!(expected, get_report().to_csv());
+    }
+
+    #[test]
+    fn should_add_benchmark_stats() {
+        let stats = Stats::from_samples(&[Duration::from_nanos(10), Duration::from_nanos(30)]);
+        let report = PartReport {
+            stats: Some(stats),
+            ..get_report()
+        };
+
+        assert_eq!(
+            "07,2,11387,,unknown,1500,42000,2,10,20,20,10,30",
+            report.to_csv()
+        );
+        assert!(report
+            .to_json()
+            .ends_with(",\"runs\":2,\"min_ns\":10,\"median_ns\":20,\"mean_ns\":20,\"stddev_ns\":10,\"p95_ns\":30}"));
+    }
+
+    #[test]
+    fn should_escape_special_characters() {
+        assert_eq!("\"a\\\"b\\\\c\\n\"", escape_json("a\"b\\c\n"));
+        assert_eq!("\"1,\"\"2\"\"\"", escape_csv("1,\"2\""));
+        assert_eq!("12", escape_csv("12"));
+    }
+}
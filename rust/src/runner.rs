@@ -0,0 +1,165 @@
+use crate::bench::{BenchOptions, Stats};
+use crate::simulation::{export, ExportOptions, Simulation, Visualizer};
+use crate::{bench_function, time_function, DayOptions, Part, Solution};
+use anyhow::{bail, Result};
+use std::time::Duration;
+
+#[derive(Debug)]
+pub struct PartRun {
+    pub part: Part,
+    pub answer: String,
+    pub duration: Duration,
+}
+
+#[derive(Debug)]
+pub struct DayRun {
+    pub day: &'static str,
+    pub parse_duration: Duration,
+    pub parts: Vec<PartRun>,
+}
+
+#[derive(Debug)]
+pub struct PartBench {
+    pub part: Part,
+    pub stats: Stats,
+}
+
+#[derive(Debug)]
+pub struct DayBench {
+    pub day: &'static str,
+    pub parse_stats: Stats,
+    pub parts: Vec<PartBench>,
+}
+
+#[derive(Copy, Clone)]
+pub struct RegisteredDay {
+    pub day: &'static str,
+    run: fn(&str, &[Part], &DayOptions) -> Result<DayRun>,
+    bench: fn(&str, &[Part], &DayOptions, &BenchOptions) -> Result<DayBench>,
+    visualize: fn(&str, Part, &DayOptions, Duration) -> Result<()>,
+    export: fn(&str, Part, &ExportOptions) -> Result<Vec<u8>>,
+}
+
+impl RegisteredDay {
+    pub fn of<S: Solution>() -> Self {
+        Self {
+            day: S::DAY,
+            run: run_solution::<S>,
+            bench: bench_solution::<S>,
+            visualize: visualize_solution::<S>,
+            export: export_solution::<S>,
+        }
+    }
+
+    pub fn run(&self, input_data: &str, parts: &[Part], options: &DayOptions) -> Result<DayRun> {
+        (self.run)(input_data, parts, options)
+    }
+
+    pub fn bench(
+        &self,
+        input_data: &str,
+        parts: &[Part],
+        day_options: &DayOptions,
+        options: &BenchOptions,
+    ) -> Result<DayBench> {
+        (self.bench)(input_data, parts, day_options, options)
+    }
+
+    pub fn visualize(
+        &self,
+        input_data: &str,
+        part: Part,
+        options: &DayOptions,
+        delay: Duration,
+    ) -> Result<()> {
+        (self.visualize)(input_data, part, options, delay)
+    }
+
+    pub fn export(&self, input_data: &str, part: Part, options: &ExportOptions) -> Result<Vec<u8>> {
+        (self.export)(input_data, part, options)
+    }
+}
+
+fn simulation_of<S: Solution>(input: &S::Input, part: Part) -> Result<Box<dyn Simulation + '_>> {
+    match S::simulation(input, part) {
+        Some(simulation) => Ok(simulation),
+        None => bail!("Day {} part {} has no simulation", S::DAY, part),
+    }
+}
+
+fn visualize_solution<S: Solution>(
+    input_data: &str,
+    part: Part,
+    options: &DayOptions,
+    delay: Duration,
+) -> Result<()> {
+    let mut input = S::parse_input(input_data)?;
+    S::configure(&mut input, options)?;
+    simulation_of::<S>(&input, part)?;
+
+    let title = format!("Day {} part {}", S::DAY, part);
+    let mut visualizer = Visualizer::new(&title, delay, || {
+        S::simulation(&input, part).expect("the simulation was available at start")
+    });
+    visualizer.run_interactive()
+}
+
+fn export_solution<S: Solution>(
+    input_data: &str,
+    part: Part,
+    options: &ExportOptions,
+) -> Result<Vec<u8>> {
+    let input = S::parse_input(input_data)?;
+    let mut simulation = simulation_of::<S>(&input, part)?;
+    export(simulation.as_mut(), options)
+}
+
+fn run_solution<S: Solution>(
+    input_data: &str,
+    parts: &[Part],
+    options: &DayOptions,
+) -> Result<DayRun> {
+    let (mut input, parse_duration) = time_function!(S::parse_input(input_data)?);
+    S::configure(&mut input, options)?;
+
+    let mut part_runs = Vec::new();
+    for part in parts {
+        let (answer, duration) = time_function!(S::solve(*part, &input)?);
+        part_runs.push(PartRun {
+            part: *part,
+            answer,
+            duration,
+        });
+    }
+
+    Ok(DayRun {
+        day: S::DAY,
+        parse_duration,
+        parts: part_runs,
+    })
+}
+
+fn bench_solution<S: Solution>(
+    input_data: &str,
+    parts: &[Part],
+    day_options: &DayOptions,
+    options: &BenchOptions,
+) -> Result<DayBench> {
+    let parse_stats = bench_function!(options, S::parse_input(input_data));
+    let mut input = S::parse_input(input_data)?;
+    S::configure(&mut input, day_options)?;
+
+    let parts = parts
+        .iter()
+        .map(|part| PartBench {
+            part: *part,
+            stats: bench_function!(options, S::solve(*part, &input)),
+        })
+        .collect();
+
+    Ok(DayBench {
+        day: S::DAY,
+        parse_stats,
+        parts,
+    })
+}
@@ -0,0 +1,258 @@
+use crate::answers::ANSWERS_FILE_NAME;
+use crate::input::input_file_name;
+use anyhow::{anyhow, bail, Context, Result};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const TEMPLATE_MARKER: &str = " // TODO: Replace with actual day number";
+const TEMPLATE_DAY: &str = "NN";
+pub const EXAMPLE_FILE_NAME: &str = "example.txt";
+
+pub fn crate_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+}
+
+// Fixtures live next to rust/ and python/ so that both can use them
+pub fn examples_dir() -> PathBuf {
+    crate_dir().join("../examples")
+}
+
+pub fn day_examples_dir(examples_dir: &Path, day: &str) -> PathBuf {
+    examples_dir.join(format!("day{}", day))
+}
+
+pub fn instantiate_template(template: &str, day: &str) -> String {
+    template
+        .replace(TEMPLATE_MARKER, "")
+        .replace(TEMPLATE_DAY, day)
+}
+
+// Adds the day module, its registry entry and its fixture tests, keeping them sorted
+pub fn register_day(days_module: &str, day: &str) -> Result<String> {
+    let module_line = format!("mod day_{};", day);
+    let registry_line = format!("        RegisteredDay::of::<day_{0}::Day{0}>(),", day);
+    let fixture_line = format!("        day_{0}::Day{0},", day);
+    if days_module.lines().any(|line| line == module_line) {
+        bail!("Day {} is already registered", day);
+    }
+
+    let mut lines: Vec<String> = days_module.lines().map(str::to_string).collect();
+    insert_sorted(&mut lines, &module_line, |line| {
+        line.starts_with("mod day_")
+    })?;
+    insert_sorted(&mut lines, &registry_line, |line| {
+        line.trim_start().starts_with("RegisteredDay::of::<day_")
+    })?;
+    insert_sorted(&mut lines, &fixture_line, |line| {
+        line.starts_with("        day_") && line.ends_with(',')
+    })?;
+
+    let mut module = lines.join("\n");
+    module.push('\n');
+    Ok(module)
+}
+
+fn insert_sorted(
+    lines: &mut Vec<String>,
+    new_line: &str,
+    is_entry: impl Fn(&str) -> bool,
+) -> Result<()> {
+    let entries: Vec<usize> = (0..lines.len()).filter(|i| is_entry(&lines[*i])).collect();
+    let last = *entries
+        .last()
+        .ok_or_else(|| anyhow!("Could not find where to insert {}", new_line.trim()))?;
+    let index = entries
+        .into_iter()
+        .find(|i| lines[*i].as_str() > new_line)
+        .unwrap_or(last + 1);
+    lines.insert(index, new_line.to_string());
+    Ok(())
+}
+
+pub fn example_answers_stub(day: &str) -> String {
+    format!(
+        "[\"{}\".\"{}\"]\n# part_1 = \"\"\n# part_2 = \"\"\n",
+        day, EXAMPLE_FILE_NAME
+    )
+}
+
+// Writes the file unless it already exists, returns whether it was written
+fn create_file(path: &Path, content: &str) -> Result<bool> {
+    if path.exists() {
+        return Ok(false);
+    }
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("Could not create {}", parent.display()))?;
+    }
+    fs::write(path, content).with_context(|| format!("Could not write {}", path.display()))?;
+    Ok(true)
+}
+
+pub struct ScaffoldPaths {
+    pub crate_dir: PathBuf,
+    pub examples_dir: PathBuf,
+    pub data_dir: Option<PathBuf>,
+}
+
+// Creates the solution, input and fixture files of a new day, returns the created files
+pub fn scaffold_day(day: &str, paths: &ScaffoldPaths) -> Result<Vec<PathBuf>> {
+    let days_dir = paths.crate_dir.join("src/days");
+    let solution_path = days_dir.join(format!("day_{}.rs", day));
+    if solution_path.exists() {
+        bail!("{} already exists", solution_path.display());
+    }
+
+    let template_path = paths.crate_dir.join("templates/day_NN.rs");
+    let template = fs::read_to_string(&template_path)
+        .with_context(|| format!("Could not read template {}", template_path.display()))?;
+    let days_module_path = days_dir.join("mod.rs");
+    let days_module = fs::read_to_string(&days_module_path)
+        .with_context(|| format!("Could not read {}", days_module_path.display()))?;
+    let days_module = register_day(&days_module, day)?;
+
+    let mut created = Vec::new();
+    create_file(&solution_path, &instantiate_template(&template, day))?;
+    created.push(solution_path);
+    fs::write(&days_module_path, days_module)
+        .with_context(|| format!("Could not write {}", days_module_path.display()))?;
+
+    let examples_dir = day_examples_dir(&paths.examples_dir, day);
+    let mut files = vec![
+        (examples_dir.join(EXAMPLE_FILE_NAME), String::new()),
+        (
+            examples_dir.join(ANSWERS_FILE_NAME),
+            example_answers_stub(day),
+        ),
+    ];
+    if let Some(data_dir) = &paths.data_dir {
+        files.push((data_dir.join(input_file_name(day)), String::new()));
+    }
+    for (path, content) in files {
+        if create_file(&path, &content)? {
+            created.push(path);
+        }
+    }
+
+    Ok(created)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DAYS_MODULE: &str = "use crate::runner::RegisteredDay;
+
+mod day_01;
+mod day_15;
+
+pub fn registry() -> Vec<RegisteredDay> {
+    vec![
+        RegisteredDay::of::<day_01::Day01>(),
+        RegisteredDay::of::<day_15::Day15>(),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    crate::fixture_tests!(
+        day_01::Day01,
+        day_15::Day15,
+    );
+}
+";
+
+    #[test]
+    fn should_instantiate_template() {
+        let template = "const DAY: &str = \"NN\"; // TODO: Replace with actual day number
+
+pub struct DayNN; // TODO: Replace with actual day number
+
+impl Solution for DayNN {}
+";
+
+        assert_eq!(
+            "const DAY: &str = \"16\";
+
+pub struct Day16;
+
+impl Solution for Day16 {}
+",
+            instantiate_template(template, "16")
+        );
+    }
+
+    #[test]
+    fn should_register_day_in_order() {
+        let expected = "use crate::runner::RegisteredDay;
+
+mod day_01;
+mod day_02;
+mod day_15;
+
+pub fn registry() -> Vec<RegisteredDay> {
+    vec![
+        RegisteredDay::of::<day_01::Day01>(),
+        RegisteredDay::of::<day_02::Day02>(),
+        RegisteredDay::of::<day_15::Day15>(),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    crate::fixture_tests!(
+        day_01::Day01,
+        day_02::Day02,
+        day_15::Day15,
+    );
+}
+";
+
+        assert_eq!(expected, register_day(DAYS_MODULE, "02").unwrap());
+        assert!(register_day(DAYS_MODULE, "15").is_err());
+
+        let module = register_day(DAYS_MODULE, "16").unwrap();
+        assert!(module.contains("mod day_15;\nmod day_16;\n"));
+        assert!(module.contains("Day15>(),\n        RegisteredDay::of::<day_16::Day16>(),\n"));
+        assert!(module.contains("day_15::Day15,\n        day_16::Day16,\n    );"));
+    }
+
+    #[test]
+    fn should_scaffold_day() {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        let crate_dir = root.join("rust");
+        fs::create_dir_all(crate_dir.join("src/days")).unwrap();
+        fs::create_dir_all(crate_dir.join("templates")).unwrap();
+        fs::create_dir_all(root.join("data")).unwrap();
+        fs::write(crate_dir.join("src/days/mod.rs"), DAYS_MODULE).unwrap();
+        fs::write(
+            crate_dir.join("templates/day_NN.rs"),
+            "pub struct DayNN; // TODO: Replace with actual day number\n",
+        )
+        .unwrap();
+        let paths = ScaffoldPaths {
+            crate_dir: crate_dir.clone(),
+            examples_dir: root.join("examples"),
+            data_dir: Some(root.join("data")),
+        };
+
+        let created = scaffold_day("16", &paths).unwrap();
+
+        assert_eq!(4, created.len());
+        assert_eq!(
+            "pub struct Day16;\n",
+            fs::read_to_string(crate_dir.join("src/days/day_16.rs")).unwrap()
+        );
+        assert!(fs::read_to_string(crate_dir.join("src/days/mod.rs"))
+            .unwrap()
+            .contains("mod day_16;"));
+        assert!(root.join("data/input-16.txt").exists());
+        assert_eq!(
+            example_answers_stub("16"),
+            fs::read_to_string(root.join("examples/day16/answers.toml")).unwrap()
+        );
+        assert!(scaffold_day("16", &paths).is_err());
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,296 @@
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+// Cost of reaching every visited node from the start, and how it was reached
+#[derive(Debug)]
+pub struct SearchResult<N, C = usize> {
+    pub order: Vec<N>,
+    pub distances: HashMap<N, C>,
+    pub predecessors: HashMap<N, N>,
+}
+
+impl<N: Clone + Eq + Hash, C: Copy> SearchResult<N, C> {
+    fn new() -> Self {
+        Self {
+            order: Vec::new(),
+            distances: HashMap::new(),
+            predecessors: HashMap::new(),
+        }
+    }
+
+    pub fn distance(&self, node: &N) -> Option<C> {
+        self.distances.get(node).copied()
+    }
+
+    pub fn contains(&self, node: &N) -> bool {
+        self.distances.contains_key(node)
+    }
+
+    // Path from the start to the target, both included
+    pub fn path_to(&self, target: &N) -> Option<Vec<N>> {
+        if !self.contains(target) {
+            return None;
+        }
+        Some(reconstruct_path(&self.predecessors, target.clone()))
+    }
+}
+
+fn reconstruct_path<N: Clone + Eq + Hash>(predecessors: &HashMap<N, N>, target: N) -> Vec<N> {
+    let mut path = vec![target];
+    while let Some(predecessor) = predecessors.get(path.last().unwrap()) {
+        path.push(predecessor.clone());
+    }
+    path.reverse();
+    path
+}
+
+// Breadth-first search, distances are the number of steps from the start
+pub fn bfs<N, I>(start: N, mut successors: impl FnMut(&N) -> I) -> SearchResult<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut result = SearchResult::new();
+    result.distances.insert(start.clone(), 0);
+
+    let mut queue = VecDeque::from([start]);
+    while let Some(node) = queue.pop_front() {
+        let distance = result.distances[&node];
+        for successor in successors(&node) {
+            if let Entry::Vacant(entry) = result.distances.entry(successor.clone()) {
+                entry.insert(distance + 1);
+                result.predecessors.insert(successor.clone(), node.clone());
+                queue.push_back(successor);
+            }
+        }
+        result.order.push(node);
+    }
+
+    result
+}
+
+// Depth-first search, distances are the depths in the search tree (not the shortest ones)
+pub fn dfs<N, I>(start: N, mut successors: impl FnMut(&N) -> I) -> SearchResult<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut result = SearchResult::new();
+
+    let mut stack = vec![(start, None, 0)];
+    while let Some((node, predecessor, depth)) = stack.pop() {
+        if result.contains(&node) {
+            continue;
+        }
+        result.distances.insert(node.clone(), depth);
+        if let Some(predecessor) = predecessor {
+            result.predecessors.insert(node.clone(), predecessor);
+        }
+
+        let mut next: Vec<N> = successors(&node)
+            .into_iter()
+            .filter(|successor| !result.contains(successor))
+            .collect();
+        // Reversed so that successors are explored in the order they are given
+        next.reverse();
+        for successor in next {
+            stack.push((successor, Some(node.clone()), depth + 1));
+        }
+        result.order.push(node);
+    }
+
+    result
+}
+
+// Shortest paths with non-negative costs, successors come with the cost of the step
+pub fn dijkstra<N, C, I>(start: N, mut successors: impl FnMut(&N) -> I) -> SearchResult<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut result = SearchResult::new();
+    let mut best: HashMap<N, C> = HashMap::from([(start.clone(), C::default())]);
+    let mut predecessors: HashMap<N, N> = HashMap::new();
+
+    // Nodes live in a vec so that the heap only needs to order costs
+    let mut nodes = vec![start];
+    let mut heap = BinaryHeap::from([Reverse((C::default(), 0usize))]);
+    while let Some(Reverse((cost, index))) = heap.pop() {
+        let node = nodes[index].clone();
+        if result.contains(&node) || best[&node] < cost {
+            continue;
+        }
+        result.distances.insert(node.clone(), cost);
+        if let Some(predecessor) = predecessors.get(&node) {
+            result
+                .predecessors
+                .insert(node.clone(), predecessor.clone());
+        }
+
+        for (successor, step_cost) in successors(&node) {
+            let successor_cost = cost + step_cost;
+            if result.contains(&successor) {
+                continue;
+            }
+            if best
+                .get(&successor)
+                .is_some_and(|known| *known <= successor_cost)
+            {
+                continue;
+            }
+            best.insert(successor.clone(), successor_cost);
+            predecessors.insert(successor.clone(), node.clone());
+            heap.push(Reverse((successor_cost, nodes.len())));
+            nodes.push(successor);
+        }
+        result.order.push(node);
+    }
+
+    result
+}
+
+// Shortest path to the first goal reached, guided by an admissible heuristic
+pub fn astar<N, C, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<(Vec<N>, C)>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut best: HashMap<N, C> = HashMap::from([(start.clone(), C::default())]);
+    let mut predecessors: HashMap<N, N> = HashMap::new();
+
+    let mut heap = BinaryHeap::from([Reverse((heuristic(&start), C::default(), 0usize))]);
+    let mut nodes = vec![start];
+    while let Some(Reverse((_, cost, index))) = heap.pop() {
+        let node = nodes[index].clone();
+        if best[&node] < cost {
+            continue;
+        }
+        if is_goal(&node) {
+            return Some((reconstruct_path(&predecessors, node), cost));
+        }
+
+        for (successor, step_cost) in successors(&node) {
+            let successor_cost = cost + step_cost;
+            if best
+                .get(&successor)
+                .is_some_and(|known| *known <= successor_cost)
+            {
+                continue;
+            }
+            best.insert(successor.clone(), successor_cost);
+            predecessors.insert(successor.clone(), node.clone());
+            let estimate = successor_cost + heuristic(&successor);
+            heap.push(Reverse((estimate, successor_cost, nodes.len())));
+            nodes.push(successor);
+        }
+    }
+
+    None
+}
+
+// Number of distinct paths from the start to any target, the graph must be acyclic
+pub fn count_paths<N, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut is_target: impl FnMut(&N) -> bool,
+) -> usize
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    fn count<N: Clone + Eq + Hash, I: IntoIterator<Item = N>>(
+        node: &N,
+        successors: &mut impl FnMut(&N) -> I,
+        is_target: &mut impl FnMut(&N) -> bool,
+        cache: &mut HashMap<N, usize>,
+    ) -> usize {
+        if let Some(paths) = cache.get(node) {
+            return *paths;
+        }
+        let paths = if is_target(node) {
+            1
+        } else {
+            let next: Vec<N> = successors(node).into_iter().collect();
+            next.iter()
+                .map(|successor| count(successor, successors, is_target, cache))
+                .sum()
+        };
+        cache.insert(node.clone(), paths);
+        paths
+    }
+
+    count(&start, &mut successors, &mut is_target, &mut HashMap::new())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0 -> 1 -> 3 -> 4
+    //  \-> 2 ---^
+    fn graph(node: &u32) -> Vec<(u32, u32)> {
+        match node {
+            0 => vec![(1, 1), (2, 5)],
+            1 => vec![(3, 10)],
+            2 => vec![(3, 1)],
+            3 => vec![(4, 1)],
+            _ => vec![],
+        }
+    }
+
+    fn unweighted(node: &u32) -> Vec<u32> {
+        graph(node).into_iter().map(|(next, _)| next).collect()
+    }
+
+    #[test]
+    fn should_find_shortest_steps_with_bfs() {
+        let result = bfs(0, unweighted);
+
+        assert_eq!(vec![0, 1, 2, 3, 4], result.order);
+        assert_eq!(Some(3), result.distance(&4));
+        assert_eq!(Some(vec![0, 1, 3, 4]), result.path_to(&4));
+        assert_eq!(None, result.path_to(&5));
+    }
+
+    #[test]
+    fn should_visit_depth_first_with_dfs() {
+        let result = dfs(0, unweighted);
+
+        assert_eq!(vec![0, 1, 3, 4, 2], result.order);
+        assert_eq!(Some(vec![0, 1, 3, 4]), result.path_to(&4));
+    }
+
+    #[test]
+    fn should_find_cheapest_path_with_dijkstra() {
+        let result = dijkstra(0, graph);
+
+        assert_eq!(Some(7), result.distance(&4));
+        assert_eq!(Some(vec![0, 2, 3, 4]), result.path_to(&4));
+        assert_eq!(Some(1), result.distance(&1));
+    }
+
+    #[test]
+    fn should_find_cheapest_path_with_astar() {
+        let path = astar(0, graph, |_| 0, |node| *node == 4);
+
+        assert_eq!(Some((vec![0, 2, 3, 4], 7)), path);
+        assert_eq!(None, astar(4, graph, |_| 0, |node| *node == 0));
+    }
+
+    #[test]
+    fn should_count_paths() {
+        assert_eq!(2, count_paths(0, unweighted, |node| *node == 4));
+        assert_eq!(1, count_paths(0, unweighted, |node| *node == 1));
+        assert_eq!(0, count_paths(4, unweighted, |node| *node == 0));
+    }
+}
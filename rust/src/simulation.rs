@@ -0,0 +1,408 @@
+use crate::grid::Grid;
+use crate::image::{encode_gif, Image, ImageFormat, Palette};
+use anyhow::{bail, Context, Result};
+use std::io::{BufRead, Write};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::thread;
+use std::time::Duration;
+
+pub const DEFAULT_DELAY: Duration = Duration::from_millis(200);
+const MIN_DELAY: Duration = Duration::from_millis(10);
+const MAX_DELAY: Duration = Duration::from_secs(5);
+
+const CLEAR_SCREEN: &str = "\x1b[2J\x1b[H";
+const BOLD: &str = "\x1b[1m";
+const DIM: &str = "\x1b[2m";
+const RESET: &str = "\x1b[0m";
+
+const HELP: &str = "[enter] step  [n <count>] step n  [p] play/pause  [+/-] speed  [g <tick>] jump  [r] restart  [q] quit";
+
+// A step-by-step process that can be rendered as text, like a guard patrolling a map
+pub trait Simulation {
+    // Advances one step and returns true, or returns false without changing anything once the
+    // simulation is over
+    fn tick(&mut self) -> bool;
+
+    fn render(&self) -> String;
+
+    // The tiles drawn when exporting images
+    fn tiles(&self) -> Grid<char>;
+
+    fn palette(&self) -> Palette {
+        Palette::default()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ExportOptions {
+    pub format: ImageFormat,
+    // First exported tick
+    pub tick: usize,
+    // Maximum number of frames of an animation
+    pub frames: usize,
+    // Size in pixels of a tile
+    pub scale: usize,
+    // Time between two frames of an animation
+    pub delay: Duration,
+}
+
+impl ExportOptions {
+    pub fn new(format: ImageFormat) -> Self {
+        Self {
+            format,
+            tick: 0,
+            frames: 100,
+            scale: 4,
+            delay: Duration::from_millis(100),
+        }
+    }
+}
+
+fn to_image(simulation: &dyn Simulation, scale: usize) -> Image {
+    Image::from_tiles(&simulation.tiles(), &simulation.palette(), scale)
+}
+
+// Encodes the state at the given tick, or the following frames up to the end for an animation
+pub fn export(simulation: &mut dyn Simulation, options: &ExportOptions) -> Result<Vec<u8>> {
+    if options.scale == 0 {
+        bail!("The scale must be at least 1");
+    }
+    for tick in 0..options.tick {
+        if !simulation.tick() {
+            bail!(
+                "The simulation ended at tick {}, before tick {}",
+                tick,
+                options.tick
+            );
+        }
+    }
+
+    match options.format {
+        ImageFormat::Ppm => Ok(to_image(simulation, options.scale).to_ppm()),
+        ImageFormat::Png => Ok(to_image(simulation, options.scale).to_png()),
+        ImageFormat::Gif => {
+            let mut frames = vec![to_image(simulation, options.scale)];
+            while frames.len() < options.frames && simulation.tick() {
+                frames.push(to_image(simulation, options.scale));
+            }
+            let delay = (options.delay.as_millis() / 10).min(u16::MAX as u128) as u16;
+            encode_gif(&frames, delay)
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Command {
+    Step(usize),
+    TogglePlay,
+    Faster,
+    Slower,
+    Jump(usize),
+    Quit,
+}
+
+impl std::str::FromStr for Command {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut words = s.split_whitespace();
+        let command = match words.next() {
+            None | Some("s") => Command::Step(1),
+            Some("n") => match words.next() {
+                Some(count) => Command::Step(count.parse().context("Invalid step count")?),
+                None => Command::Step(1),
+            },
+            Some("p") => Command::TogglePlay,
+            Some("+") => Command::Faster,
+            Some("-") => Command::Slower,
+            Some("g") => match words.next() {
+                Some(tick) => Command::Jump(tick.parse().context("Invalid tick")?),
+                None => bail!("g needs a tick"),
+            },
+            Some("r") => Command::Jump(0),
+            Some("q") => Command::Quit,
+            Some(other) => bail!("Unknown command: {}", other),
+        };
+        if let Some(extra) = words.next() {
+            bail!("Unexpected argument: {}", extra);
+        }
+        Ok(command)
+    }
+}
+
+// Plays a simulation, which is restarted from scratch to go back in time
+pub struct Visualizer<'a> {
+    title: String,
+    start: Box<dyn Fn() -> Box<dyn Simulation + 'a> + 'a>,
+    simulation: Box<dyn Simulation + 'a>,
+    tick: usize,
+    finished: bool,
+    playing: bool,
+    delay: Duration,
+    message: Option<String>,
+}
+
+impl<'a> Visualizer<'a> {
+    pub fn new(
+        title: &str,
+        delay: Duration,
+        start: impl Fn() -> Box<dyn Simulation + 'a> + 'a,
+    ) -> Self {
+        Self {
+            title: title.to_string(),
+            simulation: start(),
+            start: Box::new(start),
+            tick: 0,
+            finished: false,
+            playing: false,
+            delay,
+            message: None,
+        }
+    }
+
+    pub fn tick(&self) -> usize {
+        self.tick
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    pub fn step(&mut self) {
+        if self.finished {
+            return;
+        }
+        if self.simulation.tick() {
+            self.tick += 1;
+        } else {
+            self.finished = true;
+            self.playing = false;
+        }
+    }
+
+    pub fn jump_to(&mut self, tick: usize) {
+        if tick < self.tick {
+            self.simulation = (self.start)();
+            self.tick = 0;
+            self.finished = false;
+        }
+        while self.tick < tick && !self.finished {
+            self.step();
+        }
+    }
+
+    // Applies a command, returns false when quitting
+    pub fn apply(&mut self, command: Command) -> bool {
+        match command {
+            Command::Step(count) => {
+                self.playing = false;
+                self.jump_to(self.tick + count);
+            }
+            Command::TogglePlay => self.playing = !self.playing && !self.finished,
+            Command::Faster => self.delay = (self.delay / 2).max(MIN_DELAY),
+            Command::Slower => self.delay = (self.delay * 2).min(MAX_DELAY),
+            Command::Jump(tick) => {
+                self.playing = false;
+                self.jump_to(tick);
+            }
+            Command::Quit => return false,
+        }
+        true
+    }
+
+    pub fn frame(&self) -> String {
+        let state = if self.finished {
+            "finished"
+        } else if self.playing {
+            "playing"
+        } else {
+            "paused"
+        };
+        let mut frame = format!(
+            "{}{}{} - tick {} ({}, {} ms per tick){}\n\n{}\n",
+            CLEAR_SCREEN,
+            BOLD,
+            self.title,
+            self.tick,
+            state,
+            self.delay.as_millis(),
+            RESET,
+            self.simulation.render().trim_end()
+        );
+        if let Some(message) = &self.message {
+            frame.push_str(&format!("\n{}\n", message));
+        }
+        frame.push_str(&format!("\n{}{}{}\n> ", DIM, HELP, RESET));
+        frame
+    }
+
+    // Draws a frame after every command, and after every tick while playing
+    pub fn run(&mut self, commands: Receiver<String>, output: &mut impl Write) -> Result<()> {
+        loop {
+            write!(output, "{}", self.frame())?;
+            output.flush()?;
+            self.message = None;
+
+            let line = if self.playing {
+                match commands.recv_timeout(self.delay) {
+                    Ok(line) => line,
+                    Err(RecvTimeoutError::Timeout) => {
+                        self.step();
+                        continue;
+                    }
+                    Err(RecvTimeoutError::Disconnected) => return Ok(()),
+                }
+            } else {
+                match commands.recv() {
+                    Ok(line) => line,
+                    Err(_) => return Ok(()),
+                }
+            };
+
+            match line.parse() {
+                Ok(command) => {
+                    if !self.apply(command) {
+                        return Ok(());
+                    }
+                }
+                Err(error) => self.message = Some(format!("{:#}", error)),
+            }
+        }
+    }
+
+    // Reads the commands from stdin, one per line
+    pub fn run_interactive(&mut self) -> Result<()> {
+        let (sender, commands) = mpsc::channel();
+        thread::spawn(move || {
+            for line in std::io::stdin().lock().lines() {
+                let Ok(line) = line else { break };
+                if sender.send(line).is_err() {
+                    break;
+                }
+            }
+        });
+        self.run(commands, &mut std::io::stdout())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Counts down to zero
+    struct Countdown(usize);
+
+    impl Simulation for Countdown {
+        fn tick(&mut self) -> bool {
+            if self.0 == 0 {
+                return false;
+            }
+            self.0 -= 1;
+            true
+        }
+
+        fn render(&self) -> String {
+            self.0.to_string()
+        }
+
+        // One lit tile per remaining tick
+        fn tiles(&self) -> Grid<char> {
+            let cells = (0..5).map(|i| if i < self.0 { '#' } else { '.' }).collect();
+            Grid::from_cells(5, 1, cells).unwrap()
+        }
+    }
+
+    fn countdown<'a>() -> Visualizer<'a> {
+        Visualizer::new("Countdown", DEFAULT_DELAY, || Box::new(Countdown(5)))
+    }
+
+    #[test]
+    fn should_parse_commands() {
+        assert_eq!(Command::Step(1), "".parse().unwrap());
+        assert_eq!(Command::Step(10), "n 10".parse().unwrap());
+        assert_eq!(Command::Jump(42), "g 42".parse().unwrap());
+        assert_eq!(Command::Jump(0), "r".parse().unwrap());
+        assert_eq!(Command::TogglePlay, " p ".parse().unwrap());
+        assert!("g".parse::<Command>().is_err());
+        assert!("x".parse::<Command>().is_err());
+        assert!("q now".parse::<Command>().is_err());
+    }
+
+    #[test]
+    fn should_step_and_jump() {
+        let mut visualizer = countdown();
+
+        visualizer.apply(Command::Step(2));
+        assert_eq!(2, visualizer.tick());
+        assert!(visualizer.frame().contains("\n\n3\n"));
+
+        visualizer.apply(Command::Jump(1));
+        assert_eq!(1, visualizer.tick());
+        assert!(visualizer.frame().contains("\n\n4\n"));
+
+        visualizer.apply(Command::Jump(100));
+        assert_eq!(5, visualizer.tick());
+        assert!(visualizer.is_finished());
+        assert!(visualizer.frame().contains("(finished, 200 ms per tick)"));
+    }
+
+    #[test]
+    fn should_change_speed() {
+        let mut visualizer = countdown();
+
+        visualizer.apply(Command::Faster);
+        assert!(visualizer.frame().contains("100 ms per tick"));
+        visualizer.apply(Command::Slower);
+        visualizer.apply(Command::Slower);
+        assert!(visualizer.frame().contains("400 ms per tick"));
+    }
+
+    #[test]
+    fn should_play_until_finished() {
+        let mut visualizer = Visualizer::new("Countdown", MIN_DELAY, || Box::new(Countdown(3)));
+        let (sender, commands) = mpsc::channel();
+        thread::spawn(move || {
+            sender.send("p".to_string()).unwrap();
+            sender.send("oops".to_string()).unwrap();
+            // Give the visualizer the time to play before hanging up
+            thread::sleep(Duration::from_millis(200));
+        });
+
+        let mut output = Vec::new();
+        visualizer.run(commands, &mut output).unwrap();
+        let output = String::from_utf8(output).unwrap();
+        let tick = visualizer.tick();
+
+        assert_eq!(3, tick);
+        assert!(output.contains("Unknown command: oops"));
+        assert!(output.contains("(playing, 10 ms per tick)"));
+    }
+
+    #[test]
+    fn should_export_images() {
+        let options = ExportOptions {
+            tick: 2,
+            scale: 1,
+            ..ExportOptions::new(ImageFormat::Ppm)
+        };
+        let ppm = export(&mut Countdown(5), &options).unwrap();
+        assert_eq!(b"P6\n5 1\n255\n", &ppm[..11]);
+        assert_eq!([128, 128, 128], ppm[11 + 2 * 3..11 + 3 * 3]);
+        assert_eq!([16, 16, 32], ppm[11 + 3 * 3..11 + 4 * 3]);
+
+        let png = export(&mut Countdown(1), &ExportOptions::new(ImageFormat::Png)).unwrap();
+        assert_eq!(b"\x89PNG", &png[..4]);
+        let error = export(&mut Countdown(1), &options).unwrap_err();
+        assert_eq!(
+            "The simulation ended at tick 1, before tick 2",
+            error.to_string()
+        );
+
+        let gif = export(&mut Countdown(3), &ExportOptions::new(ImageFormat::Gif)).unwrap();
+        // The start plus 3 ticks
+        assert_eq!(
+            4,
+            gif.windows(3).filter(|w| *w == [0x21, 0xF9, 0x04]).count()
+        );
+    }
+}
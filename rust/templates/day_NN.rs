@@ -0,0 +1,40 @@
+use crate::Solution;
+use anyhow::*;
+
+const DAY: &str = "NN"; // TODO: Replace with actual day number
+
+//region Part 1
+
+fn solve_part_1(input_data: &str) -> Result<String> {
+    let lines = input_data.lines();
+    Ok(format!("{}", lines.count()))
+}
+//endregion
+
+//region Part 2
+
+fn solve_part_2(input_data: &str) -> Result<String> {
+    let lines = input_data.lines();
+    Ok(format!("{}", lines.count()))
+}
+//endregion
+
+pub struct DayNN; // TODO: Replace with actual day number
+
+impl Solution for DayNN {
+    const DAY: &'static str = DAY;
+
+    type Input = String;
+
+    fn parse_input(input_data: &str) -> Result<Self::Input> {
+        Ok(input_data.to_string())
+    }
+
+    fn solve_part_1(input_data: &Self::Input) -> Result<String> {
+        solve_part_1(input_data)
+    }
+
+    fn solve_part_2(input_data: &Self::Input) -> Result<String> {
+        solve_part_2(input_data)
+    }
+}